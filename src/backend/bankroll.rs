@@ -0,0 +1,35 @@
+/// Error used when attempting to withdraw more than a bankroll holds.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct InsufficientFunds;
+
+/// Represents the player's money, in whole chips.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Bankroll(u32);
+
+impl Bankroll {
+    /// Build a bankroll holding a starting balance.
+    pub fn new(balance: u32) -> Bankroll {
+        Bankroll(balance)
+    }
+
+    /// Get the current balance.
+    pub fn balance(&self) -> u32 {
+        self.0
+    }
+
+    /// Take chips out of the bankroll, to be wagered.
+    pub fn withdraw(&mut self, amount: u32) -> Result<(), InsufficientFunds> {
+        match self.0.checked_sub(amount) {
+            None => Err(InsufficientFunds),
+            Some(balance) => {
+                self.0 = balance;
+                Ok(())
+            }
+        }
+    }
+
+    /// Put chips into the bankroll, from a returned wager or winnings.
+    pub fn deposit(&mut self, amount: u32) {
+        self.0 += amount;
+    }
+}
@@ -1,23 +1,54 @@
+use crate::backend::bankroll::Bankroll;
+use crate::backend::card::Rank;
+use crate::backend::rules::{Double, Rules, Surrender};
 use crate::behaviour::card_iter::CardIter;
 use crate::behaviour::card_like::CardLike;
-use crate::behaviour::draw::{DrawFrom, DrawTo};
+use crate::behaviour::draw::{CannotDrawFromEmpty, DrawFrom, DrawTo};
 use crate::behaviour::interface::{Action, Event, Interface};
+use crate::behaviour::reshuffle::Reshuffle;
 use crate::behaviour::score::{Score, Value};
+use crate::behaviour::split::Split;
+use std::cmp::Ordering;
 use std::marker::PhantomData;
 
-/// Represents a game of single deck blackjack.
+/// A hand played by the player, its wager, whether it came from a split, and how it was settled
+/// early.
+struct Spot<H> {
+    hand: H,
+    wager: u32,
+    split: bool,
+    surrendered: bool,
+    even_money: bool,
+}
+
+impl<H> Spot<H> {
+    fn new(hand: H, wager: u32, split: bool) -> Spot<H> {
+        Spot {
+            hand,
+            wager,
+            split,
+            surrendered: false,
+            even_money: false,
+        }
+    }
+}
+
+/// Represents a game of blackjack, played by a set of house rules.
 pub struct Blackjack<'a, C, U, D, H>
 where
     C: CardLike,
     U: Interface<H>,
     D: DrawFrom<C>,
-    H: CardIter + Score + DrawTo<C, D>,
+    H: CardIter<Card = C> + Score + DrawTo<C, D> + Split + Default,
 {
     _c: PhantomData<C>,
     ui: &'a mut U,
     deck: &'a mut D,
-    player_hand: &'a mut H,
-    dealer_hand: &'a mut H,
+    bankroll: &'a mut Bankroll,
+    player_hands: Vec<Spot<H>>,
+    dealer_hand: H,
+    insurance: u32,
+    rules: Rules,
 }
 
 impl<'a, C, U, D, H> Blackjack<'a, C, U, D, H>
@@ -25,64 +56,356 @@ where
     C: CardLike,
     U: Interface<H>,
     D: DrawFrom<C>,
-    H: CardIter + Score + DrawTo<C, D>,
+    H: CardIter<Card = C> + Score + DrawTo<C, D> + Split + Default,
 {
-    /// Create a new game of single player blackjack.
+    /// Create a new game of single player blackjack, played by the given house rules.
+    /// Wagers are taken from, and winnings paid into, the player's bankroll.
     pub fn new(
         ui: &'a mut U,
         deck: &'a mut D,
-        player_hand: &'a mut H,
-        dealer_hand: &'a mut H,
+        bankroll: &'a mut Bankroll,
+        rules: Rules,
     ) -> Blackjack<'a, C, U, D, H> {
         Blackjack {
             _c: Default::default(),
             ui,
             deck,
-            player_hand,
-            dealer_hand,
+            bankroll,
+            player_hands: vec![Spot::new(H::default(), 0, false)],
+            dealer_hand: H::default(),
+            insurance: 0,
+            rules,
         }
     }
 
-    /// Start a game of single player blackjack.
+    /// Start a game of single player blackjack, with freshly cleared hands.
+    /// The round is only dealt if the player can cover the table minimum.
     pub fn start(&mut self) {
-        let player_score = self.player_turn();
+        self.player_hands = vec![Spot::new(H::default(), 0, false)];
+        self.dealer_hand = H::default();
+        self.insurance = 0;
+        if !self.place_wager() {
+            self.ui.send(Event::Bankroll(self.bankroll.balance()));
+            return;
+        }
+        self.deal();
+        self.offer_insurance();
+        let first_action = self.early_surrender();
+        let player_scores = if self.dealer_peek() {
+            self.player_scores()
+        } else {
+            self.player_turn(first_action)
+        };
         let dealer_score = self.dealer_turn();
-        self.determine_winner(player_score, dealer_score);
-    }
-
-    /// Make the player have their turn.
-    fn player_turn(&mut self) -> Value {
-        self.player_hand
-            .draw_from(self.deck)
-            .expect("Can't draw from empty deck.");
-        self.ui.send(Event::PlayerHand(&self.player_hand));
-        let score = self.player_hand.score();
-        match score {
-            Value::Bust => self.ui.send(Event::PlayerBust),
-            Value::Blackjack => self.ui.send(Event::PlayerBlackjack),
-            _ if Action::PlayerHit == self.ui.get_action() => return self.player_turn(),
-            _ => {}
+        self.determine_winner(player_scores, dealer_score);
+    }
+
+    /// Take the player's wager for the round, returning false if they can't cover the minimum.
+    fn place_wager(&mut self) -> bool {
+        let (min, max) = (
+            self.rules.min_bet,
+            self.rules.max_bet.min(self.bankroll.balance()),
+        );
+        if max < min {
+            return false;
+        }
+        let wager = loop {
+            let wager = self.ui.get_wager(min, max);
+            if (min..=max).contains(&wager) {
+                break wager;
+            }
+        };
+        self.bankroll
+            .withdraw(wager)
+            .expect("Wager is within the balance.");
+        self.player_hands[0].wager = wager;
+        true
+    }
+
+    /// Deal the opening two cards each, alternating between the player and the dealer.
+    /// The dealer's second card is the hole card, and is dealt face down.
+    fn deal(&mut self) {
+        self.player_draw(0);
+        self.dealer_draw();
+        self.player_draw(0);
+        self.draw_card(None);
+        let upcard = self
+            .dealer_hand
+            .iter()
+            .next()
+            .expect("Dealer has no upcard.");
+        self.ui.send(Event::DealerUpcard(upcard));
+    }
+
+    /// Draw a card into one of the player's hands and show it.
+    fn player_draw(&mut self, index: usize) {
+        self.draw_card(Some(index));
+        self.ui
+            .send(Event::PlayerHand(&self.player_hands[index].hand));
+    }
+
+    /// Draw a card into the dealer's hand and show it.
+    fn dealer_draw(&mut self) {
+        self.draw_card(None);
+        self.ui.send(Event::DealerHand(&self.dealer_hand));
+    }
+
+    /// Draw a card into one of the player's hands, or the dealer's hand if there is no index.
+    /// If the deck runs out part way through the round, the discards are shuffled back in.
+    fn draw_card(&mut self, index: Option<usize>) {
+        if self.try_draw(index).is_ok() {
+            return;
+        }
+        let in_play: Vec<&C> = self
+            .player_hands
+            .iter()
+            .flat_map(|spot| spot.hand.iter())
+            .chain(self.dealer_hand.iter())
+            .collect();
+        self.deck.reshuffle_discards(&in_play);
+        self.try_draw(index).expect("Can't draw from empty deck.");
+    }
+
+    /// Try to draw a card into one of the player's hands, or the dealer's hand.
+    fn try_draw(&mut self, index: Option<usize>) -> Result<(), CannotDrawFromEmpty> {
+        match index {
+            Some(index) => self.player_hands[index].hand.draw_from(self.deck),
+            None => self.dealer_hand.draw_from(self.deck),
+        }
+    }
+
+    /// When the dealer shows an ace, offer the player insurance, or even money on a natural.
+    fn offer_insurance(&mut self) {
+        if self.dealer_hand.iter().next().map(|c| c.get_rank()) != Some(Rank::Ace) {
+            return;
+        }
+        if self.player_score(0) == Value::Blackjack {
+            self.player_hands[0].even_money = self.ui.get_even_money();
+            return;
+        }
+        let max = (self.player_hands[0].wager / 2).min(self.bankroll.balance());
+        if max == 0 {
+            return;
+        }
+        let insurance = loop {
+            let insurance = self.ui.get_insurance(max);
+            if insurance <= max {
+                break insurance;
+            }
         };
+        self.bankroll
+            .withdraw(insurance)
+            .expect("Insurance is within the balance.");
+        self.insurance = insurance;
+    }
+
+    /// Check if the dealer's upcard is an ace or worth ten, so they could have a natural.
+    fn is_dealer_peeking(&self) -> bool {
+        let upcard = self.dealer_hand.iter().next().map(|c| c.get_rank());
+        self.rules.peek
+            && matches!(
+                upcard,
+                Some(Rank::Ace | Rank::Ten | Rank::Jack | Rank::Queen | Rank::King)
+            )
+    }
+
+    /// With early surrender, the player makes their first decision before the dealer peeks.
+    /// Any action other than surrendering is only taken once the dealer is known not to have
+    /// a natural.
+    fn early_surrender(&mut self) -> Option<Action> {
+        let score = self.player_score(0);
+        if self.rules.surrender != Surrender::Early
+            || !self.is_dealer_peeking()
+            || score == Value::Blackjack
+        {
+            return None;
+        }
+        match self.player_action(0, score) {
+            Action::PlayerSurrender => {
+                self.player_hands[0].surrendered = true;
+                None
+            }
+            action => Some(action),
+        }
+    }
+
+    /// Have the dealer peek at their hole card, returning if they have a natural.
+    fn dealer_peek(&self) -> bool {
+        self.is_dealer_peeking() && self.dealer_hand.score() == Value::Blackjack
+    }
+
+    /// Score the player's hands without playing them, as the dealer has a natural.
+    fn player_scores(&mut self) -> Vec<Value> {
+        let scores: Vec<Value> = (0..self.player_hands.len())
+            .map(|index| self.player_score(index))
+            .collect();
+        if scores.contains(&Value::Blackjack) {
+            self.ui.send(Event::PlayerBlackjack);
+        }
+        scores
+    }
+
+    /// Make the player have their turn, playing each of their hands in order.
+    /// The first action may already have been chosen, before the dealer peeked.
+    fn player_turn(&mut self, mut first_action: Option<Action>) -> Vec<Value> {
+        let mut scores = Vec::new();
+        while scores.len() < self.player_hands.len() {
+            if !scores.is_empty() {
+                self.ui.send(Event::CurrentHand(scores.len()));
+            }
+            scores.push(self.play_hand(scores.len(), first_action.take()));
+        }
+        scores
+    }
+
+    /// Play one of the player's hands until it stands, busts or is surrendered.
+    fn play_hand(&mut self, index: usize, mut first_action: Option<Action>) -> Value {
+        if self.player_hands[index].surrendered {
+            return self.player_score(index);
+        }
+        loop {
+            // Hands from a split only get their second card once they are played.
+            if self.player_hands[index].hand.iter().len() < 2 {
+                self.player_draw(index);
+            }
+            let score = self.player_score(index);
+            match score {
+                Value::Bust => self.ui.send(Event::PlayerBust),
+                Value::Blackjack => self.ui.send(Event::PlayerBlackjack),
+                Value::Points(21, _) => {}
+                Value::Points(..) => match first_action
+                    .take()
+                    .unwrap_or_else(|| self.player_action(index, score))
+                {
+                    Action::PlayerHit => {
+                        self.player_draw(index);
+                        continue;
+                    }
+                    Action::PlayerDouble => return self.player_double(index),
+                    Action::PlayerSplit => {
+                        self.player_split(index);
+                        continue;
+                    }
+                    Action::PlayerSurrender => self.player_hands[index].surrendered = true,
+                    Action::PlayerStay => {}
+                },
+            };
+            return score;
+        }
+    }
+
+    /// Score one of the player's hands.
+    fn player_score(&self, index: usize) -> Value {
+        self.player_hands[index].hand.score()
+    }
+
+    /// Ask the player for an action, until they pick one that is on offer.
+    /// Split aces stand on their second card unless the rules say otherwise.
+    fn player_action(&mut self, index: usize, score: Value) -> Action {
+        let mut actions = vec![Action::PlayerHit, Action::PlayerStay];
+        if self.is_split_ace(index) && !self.rules.hit_split_aces {
+            actions = vec![Action::PlayerStay];
+        } else if self.is_player_doubling_allowed(index, score) {
+            actions.push(Action::PlayerDouble);
+        }
+        if self.is_player_splitting_allowed(index) {
+            actions.push(Action::PlayerSplit);
+        }
+        if self.is_player_surrendering_allowed(index) {
+            actions.push(Action::PlayerSurrender);
+        }
+        if actions == [Action::PlayerStay] {
+            return Action::PlayerStay;
+        }
+        loop {
+            let action = self.ui.get_action(&actions);
+            if actions.contains(&action) {
+                return action;
+            }
+        }
+    }
+
+    /// Check if one of the player's hands is an ace split from a pair of aces.
+    fn is_split_ace(&self, index: usize) -> bool {
+        let spot = &self.player_hands[index];
+        spot.split && spot.hand.iter().next().map(|c| c.get_rank()) == Some(Rank::Ace)
+    }
+
+    /// Doubling down is only allowed on the first two cards, and after a split if the rules say so.
+    fn is_player_doubling_allowed(&self, index: usize, score: Value) -> bool {
+        let spot = &self.player_hands[index];
+        spot.hand.iter().len() == 2
+            && spot.wager <= self.bankroll.balance()
+            && (!spot.split || self.rules.double_after_split)
+            && match (self.rules.double, score) {
+                (Double::Any, _) => true,
+                (Double::NineToEleven, Value::Points(p, _)) => (9..=11).contains(&p),
+                (Double::TenToEleven, Value::Points(p, _)) => (10..=11).contains(&p),
+                (_, _) => false,
+            }
+    }
+
+    /// Splitting is only allowed on a pair, up to the maximum number of hands.
+    fn is_player_splitting_allowed(&self, index: usize) -> bool {
+        let mut cards = self.player_hands[index].hand.iter();
+        let is_pair = cards.len() == 2
+            && cards.next().map(|c| c.get_rank()) == cards.next().map(|c| c.get_rank());
+        is_pair
+            && self.player_hands[index].wager <= self.bankroll.balance()
+            && self.player_hands.len() < self.rules.max_hands
+            && (!self.is_split_ace(index) || self.rules.resplit_aces)
+    }
+
+    /// Surrendering is only allowed on the first two cards, before any split.
+    fn is_player_surrendering_allowed(&self, index: usize) -> bool {
+        self.rules.surrender != Surrender::NotAllowed
+            && self.player_hands.len() == 1
+            && self.player_hands[index].hand.iter().len() == 2
+            && !self.player_hands[index].split
+    }
+
+    /// Double the wager, and draw exactly one more card.
+    fn player_double(&mut self, index: usize) -> Value {
+        let spot = &mut self.player_hands[index];
+        self.bankroll
+            .withdraw(spot.wager)
+            .expect("Doubling is within the balance.");
+        spot.wager *= 2;
+        self.ui.send(Event::PlayerDouble);
+        self.player_draw(index);
+        let score = self.player_score(index);
+        if score == Value::Bust {
+            self.ui.send(Event::PlayerBust);
+        }
         score
     }
 
-    fn is_dealer_hitting(&self, points: u8, is_soft: bool) -> bool {
-        points < 17 || is_soft && points == 17
+    /// Split a pair into two hands, to be played one after the other.
+    fn player_split(&mut self, index: usize) {
+        let spot = &mut self.player_hands[index];
+        self.bankroll
+            .withdraw(spot.wager)
+            .expect("Splitting is within the balance.");
+        let hand = spot.hand.split();
+        spot.split = true;
+        let wager = spot.wager;
+        self.player_hands
+            .insert(index + 1, Spot::new(hand, wager, true));
+        self.ui.send(Event::PlayerSplit);
+        self.ui.send(Event::CurrentHand(index));
     }
 
-    /// Make the dealer have their turn.
+    /// Make the dealer have their turn, starting by turning over the hole card.
     fn dealer_turn(&mut self) -> Value {
+        self.ui.send(Event::DealerReveal(&self.dealer_hand));
         loop {
-            self.dealer_hand
-                .draw_from(self.deck)
-                .expect("Can't draw from empty deck.");
-            self.ui.send(Event::DealerHand(&self.dealer_hand));
             let score = self.dealer_hand.score();
             match score {
                 Value::Bust => self.ui.send(Event::DealerBust),
                 Value::Blackjack => self.ui.send(Event::DealerBlackjack),
-                Value::Points(s, v) if self.is_dealer_hitting(s, v) => {
+                Value::Points(s, v) if self.rules.is_dealer_hitting(s, v) => {
                     self.ui.send(Event::DealerHit);
+                    self.dealer_draw();
                     continue;
                 }
                 _ => self.ui.send(Event::DealerStay),
@@ -91,17 +414,83 @@ where
         }
     }
 
-    /// Determine the winner of the game.
-    fn determine_winner(&mut self, player_score: Value, dealer_score: Value) {
-        match (player_score, dealer_score) {
-            (p, d) if p == d => self.ui.send(Event::Tie),
-            (Value::Blackjack, _) => self.ui.send(Event::PlayerWin),
-            (_, Value::Blackjack) => self.ui.send(Event::PlayerLoose),
-            (Value::Bust, _) => self.ui.send(Event::PlayerLoose),
-            (_, Value::Bust) => self.ui.send(Event::PlayerWin),
-            (Value::Points(p, _), Value::Points(d, _)) if p > d => self.ui.send(Event::PlayerWin),
-            (Value::Points(p, _), Value::Points(d, _)) if p < d => self.ui.send(Event::PlayerLoose),
-            _ => unreachable!(),
+    /// Settle any insurance, then determine the winner of each of the player's hands and pay
+    /// them out into the bankroll.
+    fn determine_winner(&mut self, player_scores: Vec<Value>, dealer_score: Value) {
+        match (self.insurance, dealer_score) {
+            (0, _) => {}
+            (insurance, Value::Blackjack) => {
+                self.bankroll.deposit(insurance * 3);
+                self.ui.send(Event::InsuranceWin);
+            }
+            (_, _) => self.ui.send(Event::InsuranceLoose),
+        }
+        let is_split = player_scores.len() > 1;
+        for (index, player_score) in player_scores.into_iter().enumerate() {
+            if is_split {
+                self.ui.send(Event::CurrentHand(index));
+            }
+            let wager = self.player_hands[index].wager;
+            if self.player_hands[index].surrendered && self.is_surrender_lost(dealer_score) {
+                self.ui.send(Event::PlayerLoose);
+            } else if self.player_hands[index].surrendered {
+                self.bankroll.deposit(wager / 2);
+                self.ui.send(Event::PlayerSurrender);
+            } else if self.player_hands[index].even_money {
+                self.bankroll.deposit(wager * 2);
+                self.ui.send(Event::PlayerEvenMoney);
+            } else {
+                self.determine_hand_winner(wager, player_score, dealer_score);
+            }
+        }
+        self.ui.send(Event::Bankroll(self.bankroll.balance()));
+    }
+
+    /// Late surrender only saves half the wager against a dealer without a natural. When the
+    /// dealer doesn't peek, a natural turns up after the surrender, and takes the whole wager.
+    fn is_surrender_lost(&self, dealer_score: Value) -> bool {
+        self.rules.surrender == Surrender::Late && dealer_score == Value::Blackjack
+    }
+
+    /// Determine the winner between one of the player's hands and the dealer.
+    /// A bust hand loses, even if the dealer busts too.
+    fn determine_hand_winner(&mut self, wager: u32, player_score: Value, dealer_score: Value) {
+        let (event, winnings) = match (player_score, dealer_score) {
+            (Value::Bust, _) => (Event::PlayerLoose, 0),
+            (p, d) if p == d => (Event::Tie, wager),
+            (Value::Blackjack, _) => (Event::PlayerWin, wager + self.rules.payout.pay(wager)),
+            (_, Value::Blackjack) => (Event::PlayerLoose, 0),
+            (_, Value::Bust) => (Event::PlayerWin, wager * 2),
+            (Value::Points(p, _), Value::Points(d, _)) => match p.cmp(&d) {
+                Ordering::Greater => (Event::PlayerWin, wager * 2),
+                Ordering::Less => (Event::PlayerLoose, 0),
+                Ordering::Equal => (Event::Tie, wager),
+            },
+        };
+        self.bankroll.deposit(winnings);
+        self.ui.send(event);
+    }
+}
+
+impl<'a, C, U, D, H> Blackjack<'a, C, U, D, H>
+where
+    C: CardLike,
+    U: Interface<H>,
+    D: DrawFrom<C> + Reshuffle,
+    H: CardIter<Card = C> + Score + DrawTo<C, D> + Split + Default,
+{
+    /// Play a session of rounds against the same deck, reshuffling it whenever the cut card is
+    /// reached, until the player stops or can no longer cover the table minimum.
+    pub fn play(&mut self) {
+        loop {
+            if self.deck.needs_reshuffle() {
+                self.deck.reshuffle();
+                self.ui.send(Event::Reshuffle);
+            }
+            self.start();
+            if self.bankroll.balance() < self.rules.min_bet || !self.ui.play_again() {
+                return;
+            }
         }
     }
 }
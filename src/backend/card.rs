@@ -1,8 +1,14 @@
+#[cfg(feature = "serde")]
+use crate::backend::style::{Style, Styled};
 use crate::behaviour::card_like::CardLike;
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 /// Playing card ranks.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Rank {
     Ace,
     Two,
@@ -21,6 +27,7 @@ pub enum Rank {
 
 /// Playing card suits.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Suit {
     Spade,
     Heart,
@@ -28,6 +35,18 @@ pub enum Suit {
     Diamond,
 }
 
+/// Variation selector which follows a symbol to ask for it to be drawn as an emoji.
+const EMOJI_PRESENTATION: char = '\u{FE0F}';
+
+/// Error used when parsing a rank, suit or card from text it doesn't describe.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ParseCardError {
+    InvalidRank(String),
+    InvalidSuit(String),
+}
+
+/// Playing card, of a rank and suit.
+/// With the serde feature, cards serialize in their short notation, such as "TH".
 #[derive(Debug, Eq, PartialEq, Hash)] // Don't implement Copy to prevent card duplication.
 pub struct Card {
     suit: Suit,
@@ -56,3 +75,74 @@ impl Display for Card {
         write!(f, "{:?} of {:?}s", self.rank, self.suit)
     }
 }
+
+#[cfg(feature = "serde")]
+impl Serialize for Card {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&Styled(self, Style::Short))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Card {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(|e| de::Error::custom(format!("invalid card: {:?}", e)))
+    }
+}
+
+impl FromStr for Rank {
+    type Err = ParseCardError;
+
+    /// Parse a rank from its short notation, such as "A", "7", "10" or "T", in either case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "A" => Ok(Rank::Ace),
+            "2" => Ok(Rank::Two),
+            "3" => Ok(Rank::Three),
+            "4" => Ok(Rank::Four),
+            "5" => Ok(Rank::Five),
+            "6" => Ok(Rank::Six),
+            "7" => Ok(Rank::Seven),
+            "8" => Ok(Rank::Eight),
+            "9" => Ok(Rank::Nine),
+            "10" | "T" => Ok(Rank::Ten),
+            "J" => Ok(Rank::Jack),
+            "Q" => Ok(Rank::Queen),
+            "K" => Ok(Rank::King),
+            _ => Err(ParseCardError::InvalidRank(s.to_string())),
+        }
+    }
+}
+
+impl FromStr for Suit {
+    type Err = ParseCardError;
+
+    /// Parse a suit from its initial, in either case, or its Unicode symbol, such as "s" or "♠".
+    /// Symbols may be followed by the emoji presentation selector, as in "♥️".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s
+            .trim_end_matches(EMOJI_PRESENTATION)
+            .to_uppercase()
+            .as_str()
+        {
+            "S" | "♠" | "♤" => Ok(Suit::Spade),
+            "H" | "♥" | "♡" => Ok(Suit::Heart),
+            "C" | "♣" | "♧" => Ok(Suit::Club),
+            "D" | "♦" | "♢" => Ok(Suit::Diamond),
+            _ => Err(ParseCardError::InvalidSuit(s.to_string())),
+        }
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseCardError;
+
+    /// Parse a card from its rank followed by its suit, such as "As", "10h", "Th" or "Q♦".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim_end_matches(EMOJI_PRESENTATION);
+        let (rank, suit) = trimmed.split_at(trimmed.char_indices().last().map_or(0, |(i, _)| i));
+        Ok(Card::new(suit.parse()?, rank.parse()?))
+    }
+}
@@ -0,0 +1,174 @@
+use crate::backend::card::Rank;
+use crate::backend::rules::Rules;
+use crate::behaviour::card_like::CardLike;
+
+/// The cards left in a shoe, counted by their points, from ace to ten.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Composition([u32; 10]);
+
+/// Exact chances of how the dealer's hand finishes: on 17 to 21, with a natural, or bust.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Outcomes {
+    points: [f64; 5],
+    natural: f64,
+    bust: f64,
+}
+
+impl Composition {
+    /// Count the cards in a full shoe of a number of standard decks.
+    pub fn with_decks(decks: u8) -> Composition {
+        let mut counts = [4 * decks as u32; 10];
+        counts[9] *= 4;
+        Composition(counts)
+    }
+
+    /// Count a set of cards.
+    pub fn from_cards<'a, C: CardLike + 'a>(cards: impl IntoIterator<Item = &'a C>) -> Composition {
+        let mut composition = Composition([0; 10]);
+        for card in cards {
+            composition.0[index(card.get_rank())] += 1;
+        }
+        composition
+    }
+
+    /// Get the number of cards left with the points of a rank.
+    pub fn count(&self, rank: Rank) -> u32 {
+        self.0[index(rank)]
+    }
+
+    /// Get the number of cards left.
+    pub fn len(&self) -> u32 {
+        self.0.iter().sum()
+    }
+
+    /// Check if there are no cards left.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Take out a card with the points of a rank, such as one that has been dealt.
+    /// Returns false if there are none left.
+    pub fn remove(&mut self, rank: Rank) -> bool {
+        match self.0[index(rank)].checked_sub(1) {
+            Some(count) => {
+                self.0[index(rank)] = count;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Get the chance of the next card drawn having the points of a rank.
+    pub fn chance(&self, rank: Rank) -> f64 {
+        self.count(rank) as f64 / self.len() as f64
+    }
+}
+
+impl Outcomes {
+    /// Work out the exact chances of the dealer's final hand, given their upcard and the cards
+    /// left in the shoe after it was dealt. Every way the dealer can draw out their hand is
+    /// followed, without replacement, hitting by the rules for soft 17. If the shoe could run
+    /// out before the dealer finishes, the chances add up to less than one.
+    pub fn new(rules: &Rules, shoe: &Composition, upcard: Rank) -> Outcomes {
+        let mut outcomes = Outcomes::default();
+        let value = index(upcard) as u8 + 1;
+        let mut shoe = *shoe;
+        outcomes.draw(rules, &mut shoe, value, value == 1, 1, 1.0);
+        outcomes
+    }
+
+    /// Get the chance of the dealer finishing on a total from 17 to 21, not counting naturals.
+    pub fn points(&self, points: u8) -> f64 {
+        match points {
+            17..=21 => self.points[(points - 17) as usize],
+            _ => 0.0,
+        }
+    }
+
+    /// Get the chance of the dealer having a natural.
+    pub fn natural(&self) -> f64 {
+        self.natural
+    }
+
+    /// Get the chance of the dealer busting.
+    pub fn bust(&self) -> f64 {
+        self.bust
+    }
+
+    /// Get the chances given the dealer doesn't have a natural, such as once they've peeked.
+    pub fn without_natural(&self) -> Outcomes {
+        let rest = 1.0 - self.natural;
+        if rest <= 0.0 {
+            return *self;
+        }
+        Outcomes {
+            points: self.points.map(|chance| chance / rest),
+            natural: 0.0,
+            bust: self.bust / rest,
+        }
+    }
+
+    /// Add up the chances of the dealer's final hands, by drawing every card left in the shoe.
+    /// The dealer holds `cards` cards totalling `hard`, counting aces as one.
+    fn draw(
+        &mut self,
+        rules: &Rules,
+        shoe: &mut Composition,
+        hard: u8,
+        ace: bool,
+        cards: u8,
+        chance: f64,
+    ) {
+        let points = if ace && hard + 10 <= 21 {
+            hard + 10
+        } else {
+            hard
+        };
+        if cards == 2 && points == 21 {
+            self.natural += chance;
+            return;
+        }
+        if points > 21 {
+            self.bust += chance;
+            return;
+        }
+        if cards >= 2 && !rules.is_dealer_hitting(points, points != hard) {
+            self.points[(points - 17) as usize] += chance;
+            return;
+        }
+        let left = shoe.len();
+        for value in 1..=10 {
+            let count = shoe.0[value as usize - 1];
+            if count == 0 {
+                continue;
+            }
+            shoe.0[value as usize - 1] -= 1;
+            let chance = chance * count as f64 / left as f64;
+            self.draw(
+                rules,
+                shoe,
+                hard + value,
+                ace || value == 1,
+                cards + 1,
+                chance,
+            );
+            shoe.0[value as usize - 1] += 1;
+        }
+    }
+}
+
+/// Get the index of a rank's points, with an ace at zero and tens at nine.
+fn index(rank: Rank) -> usize {
+    match rank {
+        Rank::Ace => 0,
+        Rank::Two => 1,
+        Rank::Three => 2,
+        Rank::Four => 3,
+        Rank::Five => 4,
+        Rank::Six => 5,
+        Rank::Seven => 6,
+        Rank::Eight => 7,
+        Rank::Nine => 8,
+        Rank::Ten | Rank::Jack | Rank::Queen | Rank::King => 9,
+    }
+}
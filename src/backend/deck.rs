@@ -1,7 +1,11 @@
-use crate::backend::card::{Card, Rank, Suit};
+use crate::backend::card::{Card, ParseCardError, Rank, Suit};
+use crate::behaviour::card_like::CardLike;
 use crate::behaviour::draw::DrawFrom;
+use crate::behaviour::reshuffle::Reshuffle;
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::{thread_rng, Rng, SeedableRng};
+use std::str::FromStr;
 
 const RANKS: [Rank; 13] = [
     Rank::Ace,
@@ -21,24 +25,174 @@ const RANKS: [Rank; 13] = [
 
 const SUITS: [Suit; 4] = [Suit::Spade, Suit::Heart, Suit::Club, Suit::Diamond];
 
+/// The number of cards in one standard deck.
+pub const DECK_SIZE: usize = SUITS.len() * RANKS.len();
+
+/// Error used when a deck spec, such as "AS KH 10D 5C", holds something that isn't a card.
+/// Holds the text which couldn't be parsed, and why it isn't a card.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseDeckError(pub String, pub ParseCardError);
+
 /// Represents a deck of playing cards.
+/// A cut card is placed three quarters of the way through, and a reshuffle is due once a card
+/// past it has been dealt. Every shuffle is made from a seed, so a deck can be dealt again in the
+/// same order. The seed for each reshuffle is drawn from the seed of the deck's first shuffle.
 #[derive(Debug)]
-pub struct Deck(Vec<Card>);
+pub struct Deck {
+    cards: Vec<Card>,
+    all_cards: Vec<(Suit, Rank)>,
+    cut_card: usize,
+    seed: u64,
+    seeds: StdRng,
+}
 
 impl Deck {
     /// Build a freshly shuffled deck.
     pub fn new() -> Deck {
-        let mut deck: Vec<Card> = Vec::new();
-        SUITS
+        Deck::with_rng(&mut thread_rng())
+    }
+
+    /// Build a deck shuffled with a seed drawn from a random number generator.
+    pub fn with_rng<R: Rng + ?Sized>(rng: &mut R) -> Deck {
+        Deck::with_seed(rng.gen())
+    }
+
+    /// Build a deck shuffled from a seed.
+    /// Decks built from the same seed are dealt in the same order.
+    pub fn with_seed(seed: u64) -> Deck {
+        Deck::of_decks(1, seed)
+    }
+
+    /// Build a deck of a number of standard decks, shuffled from a seed, as a shoe holds.
+    pub(crate) fn of_decks(decks: u8, seed: u64) -> Deck {
+        let mut all_cards = Vec::new();
+        for _ in 0..decks {
+            SUITS
+                .iter()
+                .for_each(|s| RANKS.iter().for_each(|r| all_cards.push((*s, *r))));
+        }
+        let mut deck = Deck {
+            cards: Vec::new(),
+            cut_card: all_cards.len() * 3 / 4 + 1,
+            all_cards,
+            seed,
+            seeds: StdRng::seed_from_u64(seed),
+        };
+        deck.shuffle_from_seed();
+        deck
+    }
+
+    /// Build a stacked deck, which deals the given cards in order, first card first.
+    /// Once reshuffled, the same cards are shuffled from a seed of zero.
+    pub fn from_cards(cards: Vec<Card>) -> Deck {
+        let all_cards: Vec<(Suit, Rank)> =
+            cards.iter().map(|c| (c.get_suit(), c.get_rank())).collect();
+        let mut cards = cards;
+        cards.reverse();
+        Deck {
+            cards,
+            cut_card: all_cards.len() * 3 / 4 + 1,
+            all_cards,
+            seed: 0,
+            seeds: StdRng::seed_from_u64(0),
+        }
+    }
+
+    /// Move the cut card to a penetration between 0 and 1, so a reshuffle is due once that
+    /// fraction of the deck has been dealt. Penetrations outside that range are clamped to it.
+    pub(crate) fn with_penetration(self, penetration: f64) -> Deck {
+        let penetration = if penetration.is_nan() {
+            1.0
+        } else {
+            penetration.clamp(0.0, 1.0)
+        };
+        Deck {
+            cut_card: (self.all_cards.len() as f64 * penetration).round() as usize,
+            ..self
+        }
+    }
+
+    /// Get the number of cards in the full deck.
+    pub fn size(&self) -> usize {
+        self.all_cards.len()
+    }
+
+    /// Get the number of cards left to be dealt.
+    pub fn remaining(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Get the number of cards dealt before a reshuffle is due.
+    pub(crate) fn cut_card(&self) -> usize {
+        self.cut_card
+    }
+
+    /// Get the fraction of the deck dealt since it was last shuffled.
+    pub(crate) fn penetration(&self) -> f64 {
+        (self.size() - self.remaining()) as f64 / self.size() as f64
+    }
+
+    /// Get the seed used for the deck's current shuffle.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Gather up every card, and shuffle them from the current seed.
+    fn shuffle_from_seed(&mut self) {
+        self.cards = self
+            .all_cards
             .iter()
-            .for_each(|s| RANKS.iter().for_each(|r| deck.push(Card::new(*s, *r))));
-        deck.shuffle(&mut thread_rng());
-        Deck(deck)
+            .map(|(s, r)| Card::new(*s, *r))
+            .collect();
+        self.cards.shuffle(&mut StdRng::seed_from_u64(self.seed));
+    }
+}
+
+impl Default for Deck {
+    fn default() -> Self {
+        Deck::new()
     }
 }
 
 impl DrawFrom<Card> for Deck {
     fn draw(&mut self) -> Option<Card> {
-        self.0.pop()
+        self.cards.pop()
+    }
+
+    fn reshuffle_discards(&mut self, in_play: &[&Card]) {
+        self.seed = self.seeds.gen();
+        self.shuffle_from_seed();
+        for card in in_play {
+            if let Some(index) = self.cards.iter().position(|c| c == *card) {
+                self.cards.remove(index);
+            }
+        }
+    }
+}
+
+impl Reshuffle for Deck {
+    fn needs_reshuffle(&self) -> bool {
+        self.size() - self.remaining() >= self.cut_card
+    }
+
+    fn reshuffle(&mut self) {
+        self.seed = self.seeds.gen();
+        self.shuffle_from_seed();
+    }
+}
+
+impl FromStr for Deck {
+    type Err = ParseDeckError;
+
+    /// Parse a stacked deck from a spec of cards separated by whitespace, dealt in order.
+    /// Each card is written in the short notation parsed by `Card`, such as "AS" or "10d".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split_whitespace()
+            .map(|spec| {
+                spec.parse()
+                    .map_err(|error| ParseDeckError(spec.to_string(), error))
+            })
+            .collect::<Result<Vec<Card>, ParseDeckError>>()
+            .map(Deck::from_cards)
     }
 }
@@ -3,18 +3,32 @@ use crate::behaviour::card_iter::CardIter;
 use crate::behaviour::card_like::CardLike;
 use crate::behaviour::draw::{CannotDrawFromEmpty, DrawFrom, DrawTo};
 use crate::behaviour::score::{Score, Value};
+use crate::behaviour::split::Split;
 use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use std::slice::Iter;
 
 /// Represents a hand of playing cards.
+/// A hand split from a pair, or left after splitting one, can't score as a natural.
 #[derive(Debug)]
-pub struct Hand<C>(Vec<C>);
+pub struct Hand<C> {
+    cards: Vec<C>,
+    split: bool,
+}
 
 impl<C> Hand<C> {
     /// Build an empty hand.
     pub fn new() -> Hand<C> {
-        Hand(Vec::new())
+        Hand {
+            cards: Vec::new(),
+            split: false,
+        }
+    }
+}
+
+impl<C> Default for Hand<C> {
+    fn default() -> Self {
+        Hand::new()
     }
 }
 
@@ -25,7 +39,7 @@ where
     type Card = C;
 
     fn iter(&self) -> Iter<'_, Self::Card> {
-        self.0.iter()
+        self.cards.iter()
     }
 }
 
@@ -38,13 +52,23 @@ where
         match cards.draw() {
             None => Err(CannotDrawFromEmpty),
             Some(card) => {
-                self.0.push(card);
+                self.cards.push(card);
                 Ok(())
             }
         }
     }
 }
 
+impl<C> Split for Hand<C> {
+    fn split(&mut self) -> Self {
+        self.split = true;
+        Hand {
+            cards: self.cards.pop().into_iter().collect(),
+            split: true,
+        }
+    }
+}
+
 impl<C> Score for Hand<C>
 where
     C: CardLike,
@@ -53,7 +77,7 @@ where
         let mut points = 0;
         let mut aces = 0;
 
-        for card in &self.0[0..] {
+        for card in &self.cards {
             points += match card.get_rank() {
                 Rank::Ace => {
                     aces += 1;
@@ -83,7 +107,8 @@ where
         match points.cmp(&{ 21 }) {
             Ordering::Less => Value::Points(points, aces > 0),
             Ordering::Greater => Value::Bust,
-            Ordering::Equal => Value::Blackjack,
+            Ordering::Equal if self.cards.len() == 2 && !self.split => Value::Blackjack,
+            Ordering::Equal => Value::Points(points, aces > 0),
         }
     }
 }
@@ -93,9 +118,9 @@ where
     C: CardLike + Display,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let result = self.0.iter().fold(
+        let result = self.cards.iter().fold(
             String::new(),
-            |s, c| if s == "" { s } else { s + ", " } + &*c.to_string(),
+            |s, c| if s.is_empty() { s } else { s + ", " } + &*c.to_string(),
         );
         write!(f, "{}", result)
     }
@@ -0,0 +1,621 @@
+use crate::backend::bankroll::Bankroll;
+use crate::backend::blackjack::Blackjack;
+use crate::backend::card::{Card, Rank, Suit};
+use crate::backend::deck::Deck;
+use crate::backend::hand::Hand;
+use crate::backend::rules::{Double, Payout, Rules, Surrender};
+use crate::backend::style::{Style, Styled};
+use crate::behaviour::card_like::CardLike;
+use crate::behaviour::draw::DrawFrom;
+use crate::behaviour::interface::{Action, Event, Interface, OwnedEvent};
+use crate::behaviour::reshuffle::Reshuffle;
+use std::cell::{Cell, RefCell};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// One step of a round, either a decision made by the player or an event sent to them.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Step {
+    Wager(u32),
+    Action(Action),
+    Insurance(u32),
+    EvenMoney(bool),
+    Event(OwnedEvent<Card>),
+}
+
+/// Error used when a hand history holds a line it can't read, holding that line.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseHistoryError(pub String);
+
+/// Error used when replaying a hand history doesn't play out as it was recorded.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ReplayError {
+    /// The game asked for a different decision than the one recorded at this step.
+    Decision(usize),
+    /// The game needed more cards than were recorded, by the step it had reached.
+    OutOfCards(usize),
+    /// The game sent a different event than the one recorded at this step.
+    Event {
+        step: usize,
+        expected: String,
+        actual: String,
+    },
+}
+
+/// The structured history of a single round of blackjack.
+/// It holds everything needed to play the round again: the house rules, the player's balance
+/// going in, every card dealt in order, and each decision and event in the order they happened.
+/// Decisions the game rejected, such as a wager out of range, are kept too, just as they were made.
+///
+/// Histories are saved as text, one line per item, such as:
+/// ```text
+/// rules dealer_hits_soft_17=true decks=1 min_bet=10 max_bet=500 payout=3:2 ...
+/// bankroll 1000
+/// cards 5H TS 6D 6C 9S TD
+/// wager 20
+/// event player_hand 5H
+/// action hit
+/// event bankroll 1020
+/// ```
+#[derive(Debug, Eq, PartialEq)]
+pub struct History {
+    pub rules: Rules,
+    pub bankroll: u32,
+    pub cards: Vec<Card>,
+    pub steps: Vec<Step>,
+}
+
+impl History {
+    /// Play a round of blackjack from a deck, recording its history.
+    /// Decisions are taken from, and events sent to, the interface as usual.
+    pub fn record<U, D>(ui: &mut U, deck: &mut D, bankroll: &mut Bankroll, rules: Rules) -> History
+    where
+        U: Interface<Hand<Card>>,
+        D: DrawFrom<Card>,
+    {
+        let mut history = None;
+        let cards = RefCell::new(Vec::new());
+        let mut recorder = Recorder::new(ui, &cards, bankroll, rules, |h| history = Some(h));
+        let mut deck = RecordingDeck {
+            deck,
+            cards: &cards,
+        };
+        Blackjack::<_, _, _, Hand<Card>>::new(&mut recorder, &mut deck, bankroll, rules).start();
+        history.expect("Round ends by settling the bankroll.")
+    }
+
+    /// Play a session of rounds of blackjack from a deck, as `Blackjack::play` does, recording
+    /// the history of each round and passing it to `round` once the round is settled.
+    /// Reshuffles between rounds are sent to the interface, but aren't part of any round.
+    pub fn record_session<U, D>(
+        ui: &mut U,
+        deck: &mut D,
+        bankroll: &mut Bankroll,
+        rules: Rules,
+        round: impl FnMut(History),
+    ) where
+        U: Interface<Hand<Card>>,
+        D: DrawFrom<Card> + Reshuffle,
+    {
+        let cards = RefCell::new(Vec::new());
+        let mut recorder = Recorder::new(ui, &cards, bankroll, rules, round);
+        let mut deck = RecordingDeck {
+            deck,
+            cards: &cards,
+        };
+        Blackjack::<_, _, _, Hand<Card>>::new(&mut recorder, &mut deck, bankroll, rules).play();
+    }
+
+    /// Play the round again from its recorded cards and decisions, sending its events to an
+    /// interface. Fails on the first step which doesn't play out as it was recorded.
+    pub fn replay<U>(&self, ui: &mut U) -> Result<(), ReplayError>
+    where
+        U: Interface<Hand<Card>>,
+    {
+        let out_of_cards = Cell::new(false);
+        let mut deck = ReplayDeck {
+            deck: Deck::from_cards(self.cards.iter().map(copy).collect()),
+            out_of_cards: &out_of_cards,
+        };
+        let mut replayer = Replayer {
+            ui,
+            steps: &self.steps,
+            step: 0,
+            error: None,
+            out_of_cards: &out_of_cards,
+        };
+        Blackjack::<_, _, _, Hand<Card>>::new(
+            &mut replayer,
+            &mut deck,
+            &mut Bankroll::new(self.bankroll),
+            self.rules,
+        )
+        .start();
+        replayer.check_cards();
+        match (replayer.error, self.steps.get(replayer.step)) {
+            (Some(error), _) => Err(error),
+            (None, Some(Step::Event(event))) => Err(ReplayError::Event {
+                step: replayer.step,
+                expected: write_event(event),
+                actual: String::new(),
+            }),
+            (None, Some(_)) => Err(ReplayError::Decision(replayer.step)),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// Interface which records the decisions and events passing through another interface, along
+/// with the cards drawn for them, passing on the history of each round once it is settled.
+struct Recorder<'a, U, F> {
+    ui: &'a mut U,
+    cards: &'a RefCell<Vec<Card>>,
+    rules: Rules,
+    bankroll: u32,
+    steps: Vec<Step>,
+    round: F,
+}
+
+impl<'a, U, F> Recorder<'a, U, F>
+where
+    F: FnMut(History),
+{
+    /// Build a recorder of rounds starting from a bankroll, sharing the cards recorded by a deck.
+    fn new(
+        ui: &'a mut U,
+        cards: &'a RefCell<Vec<Card>>,
+        bankroll: &Bankroll,
+        rules: Rules,
+        round: F,
+    ) -> Recorder<'a, U, F> {
+        Recorder {
+            ui,
+            cards,
+            rules,
+            bankroll: bankroll.balance(),
+            steps: Vec::new(),
+            round,
+        }
+    }
+}
+
+impl<U, F> Interface<Hand<Card>> for Recorder<'_, U, F>
+where
+    U: Interface<Hand<Card>>,
+    F: FnMut(History),
+{
+    fn get_wager(&mut self, min: u32, max: u32) -> u32 {
+        let wager = self.ui.get_wager(min, max);
+        self.steps.push(Step::Wager(wager));
+        wager
+    }
+
+    fn get_action(&mut self, actions: &[Action]) -> Action {
+        let action = self.ui.get_action(actions);
+        self.steps.push(Step::Action(action));
+        action
+    }
+
+    fn get_insurance(&mut self, max: u32) -> u32 {
+        let insurance = self.ui.get_insurance(max);
+        self.steps.push(Step::Insurance(insurance));
+        insurance
+    }
+
+    fn get_even_money(&mut self) -> bool {
+        let even_money = self.ui.get_even_money();
+        self.steps.push(Step::EvenMoney(even_money));
+        even_money
+    }
+
+    fn play_again(&mut self) -> bool {
+        self.ui.play_again()
+    }
+
+    fn send(&mut self, event: Event<Hand<Card>>) {
+        if !matches!(event, Event::Reshuffle) {
+            self.steps
+                .push(Step::Event(OwnedEvent::from_event(&event, copy)));
+        }
+        if let Event::Bankroll(balance) = event {
+            (self.round)(History {
+                rules: self.rules,
+                bankroll: self.bankroll,
+                cards: self.cards.take(),
+                steps: std::mem::take(&mut self.steps),
+            });
+            self.bankroll = balance;
+        }
+        self.ui.send(event);
+    }
+}
+
+/// Deck which records the cards drawn from another deck.
+struct RecordingDeck<'a, D> {
+    deck: &'a mut D,
+    cards: &'a RefCell<Vec<Card>>,
+}
+
+impl<D> DrawFrom<Card> for RecordingDeck<'_, D>
+where
+    D: DrawFrom<Card>,
+{
+    fn draw(&mut self) -> Option<Card> {
+        let card = self.deck.draw()?;
+        self.cards.borrow_mut().push(copy(&card));
+        Some(card)
+    }
+
+    fn reshuffle_discards(&mut self, in_play: &[&Card]) {
+        self.deck.reshuffle_discards(in_play)
+    }
+}
+
+impl<D> Reshuffle for RecordingDeck<'_, D>
+where
+    D: Reshuffle,
+{
+    fn needs_reshuffle(&self) -> bool {
+        self.deck.needs_reshuffle()
+    }
+
+    fn reshuffle(&mut self) {
+        self.deck.reshuffle()
+    }
+}
+
+/// Interface which makes recorded decisions, and checks the events sent against those recorded,
+/// passing the events on to another interface. Decisions are made just as they were recorded,
+/// even those the game rejected, so it asks again as it did then.
+struct Replayer<'a, U> {
+    ui: &'a mut U,
+    steps: &'a [Step],
+    step: usize,
+    error: Option<ReplayError>,
+    out_of_cards: &'a Cell<bool>,
+}
+
+/// Deck which deals the recorded cards, and flags when the game needs more than were recorded.
+/// Rather than reshuffling, it then deals twos, so the round can still be played out.
+struct ReplayDeck<'a> {
+    deck: Deck,
+    out_of_cards: &'a Cell<bool>,
+}
+
+impl DrawFrom<Card> for ReplayDeck<'_> {
+    fn draw(&mut self) -> Option<Card> {
+        self.deck.draw().or_else(|| {
+            self.out_of_cards.set(true);
+            Some(Card::new(Suit::Spade, Rank::Two))
+        })
+    }
+}
+
+impl<U> Replayer<'_, U> {
+    /// Fail the replay if the deck has run out of recorded cards.
+    fn check_cards(&mut self) {
+        if self.error.is_none() && self.out_of_cards.get() {
+            self.error = Some(ReplayError::OutOfCards(self.step));
+        }
+    }
+
+    /// Take the next recorded step, if it is a decision `decide` accepts.
+    /// On a mismatch, the replay has failed, and the fallback decision is made instead.
+    fn decide<T>(&mut self, fallback: T, decide: impl Fn(&Step) -> Option<T>) -> T {
+        self.check_cards();
+        if self.error.is_none() {
+            if let Some(decision) = self.steps.get(self.step).and_then(decide) {
+                self.step += 1;
+                return decision;
+            }
+            self.error = Some(ReplayError::Decision(self.step));
+        }
+        fallback
+    }
+}
+
+impl<U> Interface<Hand<Card>> for Replayer<'_, U>
+where
+    U: Interface<Hand<Card>>,
+{
+    fn get_wager(&mut self, min: u32, _max: u32) -> u32 {
+        self.decide(min, |s| match s {
+            Step::Wager(wager) => Some(*wager),
+            _ => None,
+        })
+    }
+
+    fn get_action(&mut self, _actions: &[Action]) -> Action {
+        self.decide(Action::PlayerStay, |s| match s {
+            Step::Action(action) => Some(*action),
+            _ => None,
+        })
+    }
+
+    fn get_insurance(&mut self, _max: u32) -> u32 {
+        self.decide(0, |s| match s {
+            Step::Insurance(insurance) => Some(*insurance),
+            _ => None,
+        })
+    }
+
+    fn get_even_money(&mut self) -> bool {
+        self.decide(false, |s| match s {
+            Step::EvenMoney(even_money) => Some(*even_money),
+            _ => None,
+        })
+    }
+
+    fn play_again(&mut self) -> bool {
+        false
+    }
+
+    fn send(&mut self, event: Event<Hand<Card>>) {
+        self.check_cards();
+        if self.error.is_none() {
+            let actual = OwnedEvent::from_event(&event, copy);
+            match self.steps.get(self.step) {
+                Some(Step::Event(expected)) if *expected == actual => self.step += 1,
+                expected => {
+                    self.error = Some(ReplayError::Event {
+                        step: self.step,
+                        expected: match expected {
+                            Some(Step::Event(expected)) => write_event(expected),
+                            Some(step) => write_step(step),
+                            None => String::new(),
+                        },
+                        actual: write_event(&actual),
+                    })
+                }
+            }
+        }
+        self.ui.send(event);
+    }
+}
+
+/// Copy a card, by its suit and rank.
+fn copy<C: CardLike>(card: &C) -> Card {
+    Card::new(card.get_suit(), card.get_rank())
+}
+
+/// Write cards out in short notation, separated by spaces.
+fn write_cards(cards: &[Card]) -> String {
+    Styled(cards, Style::Short).to_string()
+}
+
+/// Write out an event as a line of a history.
+fn write_event(event: &OwnedEvent<Card>) -> String {
+    let (name, detail) = match event {
+        OwnedEvent::PlayerBust => ("player_bust", String::new()),
+        OwnedEvent::PlayerBlackjack => ("player_blackjack", String::new()),
+        OwnedEvent::PlayerDouble => ("player_double", String::new()),
+        OwnedEvent::PlayerSplit => ("player_split", String::new()),
+        OwnedEvent::CurrentHand(index) => ("current_hand", index.to_string()),
+        OwnedEvent::DealerBust => ("dealer_bust", String::new()),
+        OwnedEvent::DealerBlackjack => ("dealer_blackjack", String::new()),
+        OwnedEvent::DealerStay => ("dealer_stay", String::new()),
+        OwnedEvent::DealerHit => ("dealer_hit", String::new()),
+        OwnedEvent::PlayerWin => ("player_win", String::new()),
+        OwnedEvent::PlayerLoose => ("player_loose", String::new()),
+        OwnedEvent::Tie => ("tie", String::new()),
+        OwnedEvent::PlayerSurrender => ("player_surrender", String::new()),
+        OwnedEvent::PlayerEvenMoney => ("player_even_money", String::new()),
+        OwnedEvent::InsuranceWin => ("insurance_win", String::new()),
+        OwnedEvent::InsuranceLoose => ("insurance_loose", String::new()),
+        OwnedEvent::Bankroll(balance) => ("bankroll", balance.to_string()),
+        OwnedEvent::Reshuffle => ("reshuffle", String::new()),
+        OwnedEvent::PlayerHand(cards) => ("player_hand", write_cards(cards)),
+        OwnedEvent::DealerHand(cards) => ("dealer_hand", write_cards(cards)),
+        OwnedEvent::DealerUpcard(card) => ("dealer_upcard", Styled(card, Style::Short).to_string()),
+        OwnedEvent::DealerReveal(cards) => ("dealer_reveal", write_cards(cards)),
+    };
+    if detail.is_empty() {
+        format!("event {}", name)
+    } else {
+        format!("event {} {}", name, detail)
+    }
+}
+
+/// Write out a step as a line of a history.
+fn write_step(step: &Step) -> String {
+    match step {
+        Step::Wager(wager) => format!("wager {}", wager),
+        Step::Action(action) => format!("action {}", action_name(*action)),
+        Step::Insurance(insurance) => format!("insurance {}", insurance),
+        Step::EvenMoney(even_money) => format!("even_money {}", even_money),
+        Step::Event(event) => write_event(event),
+    }
+}
+
+/// Every action the player may take.
+const ACTIONS: [Action; 5] = [
+    Action::PlayerStay,
+    Action::PlayerHit,
+    Action::PlayerDouble,
+    Action::PlayerSplit,
+    Action::PlayerSurrender,
+];
+
+/// The name of an action in a history.
+fn action_name(action: Action) -> &'static str {
+    match action {
+        Action::PlayerHit => "hit",
+        Action::PlayerStay => "stay",
+        Action::PlayerDouble => "double",
+        Action::PlayerSplit => "split",
+        Action::PlayerSurrender => "surrender",
+    }
+}
+
+/// Write out house rules as a line of a history.
+fn write_rules(rules: &Rules) -> String {
+    let payout = match rules.payout {
+        Payout::ThreeToTwo => "3:2",
+        Payout::SixToFive => "6:5",
+        Payout::OneToOne => "1:1",
+    };
+    let double = match rules.double {
+        Double::Any => "any",
+        Double::NineToEleven => "9-11",
+        Double::TenToEleven => "10-11",
+    };
+    let surrender = match rules.surrender {
+        Surrender::NotAllowed => "none",
+        Surrender::Late => "late",
+        Surrender::Early => "early",
+    };
+    format!(
+        "rules dealer_hits_soft_17={} decks={} min_bet={} max_bet={} payout={} double={} \
+         double_after_split={} max_hands={} resplit_aces={} hit_split_aces={} surrender={} \
+         peek={}",
+        rules.dealer_hits_soft_17,
+        rules.decks,
+        rules.min_bet,
+        rules.max_bet,
+        payout,
+        double,
+        rules.double_after_split,
+        rules.max_hands,
+        rules.resplit_aces,
+        rules.hit_split_aces,
+        surrender,
+        rules.peek
+    )
+}
+
+/// Read house rules from the settings of a rules line, where settings left out keep their
+/// default.
+fn parse_rules<'a>(settings: impl Iterator<Item = &'a str>) -> Option<Rules> {
+    let mut rules = Rules::default();
+    for setting in settings {
+        let (key, value) = setting.split_once('=')?;
+        match key {
+            "dealer_hits_soft_17" => rules.dealer_hits_soft_17 = value.parse().ok()?,
+            "decks" => rules.decks = value.parse().ok()?,
+            "min_bet" => rules.min_bet = value.parse().ok()?,
+            "max_bet" => rules.max_bet = value.parse().ok()?,
+            "payout" => {
+                rules.payout = match value {
+                    "3:2" => Payout::ThreeToTwo,
+                    "6:5" => Payout::SixToFive,
+                    "1:1" => Payout::OneToOne,
+                    _ => return None,
+                }
+            }
+            "double" => {
+                rules.double = match value {
+                    "any" => Double::Any,
+                    "9-11" => Double::NineToEleven,
+                    "10-11" => Double::TenToEleven,
+                    _ => return None,
+                }
+            }
+            "double_after_split" => rules.double_after_split = value.parse().ok()?,
+            "max_hands" => rules.max_hands = value.parse().ok()?,
+            "resplit_aces" => rules.resplit_aces = value.parse().ok()?,
+            "hit_split_aces" => rules.hit_split_aces = value.parse().ok()?,
+            "surrender" => {
+                rules.surrender = match value {
+                    "none" => Surrender::NotAllowed,
+                    "late" => Surrender::Late,
+                    "early" => Surrender::Early,
+                    _ => return None,
+                }
+            }
+            "peek" => rules.peek = value.parse().ok()?,
+            _ => return None,
+        }
+    }
+    Some(rules)
+}
+
+/// Read the single value on a line.
+fn parse_one<'a, T: FromStr>(mut words: impl Iterator<Item = &'a str>) -> Option<T> {
+    let value = words.next()?.parse().ok()?;
+    words.next().is_none().then_some(value)
+}
+
+/// Read cards written in short notation.
+fn parse_cards<'a>(cards: impl Iterator<Item = &'a str>) -> Option<Vec<Card>> {
+    cards.map(|c| c.parse().ok()).collect()
+}
+
+/// Read an event from the words after "event" on a line of a history.
+fn parse_event<'a>(mut words: impl Iterator<Item = &'a str>) -> Option<OwnedEvent<Card>> {
+    let event = match words.next()? {
+        "player_bust" => OwnedEvent::PlayerBust,
+        "player_blackjack" => OwnedEvent::PlayerBlackjack,
+        "player_double" => OwnedEvent::PlayerDouble,
+        "player_split" => OwnedEvent::PlayerSplit,
+        "current_hand" => OwnedEvent::CurrentHand(words.next()?.parse().ok()?),
+        "dealer_bust" => OwnedEvent::DealerBust,
+        "dealer_blackjack" => OwnedEvent::DealerBlackjack,
+        "dealer_stay" => OwnedEvent::DealerStay,
+        "dealer_hit" => OwnedEvent::DealerHit,
+        "player_win" => OwnedEvent::PlayerWin,
+        "player_loose" => OwnedEvent::PlayerLoose,
+        "tie" => OwnedEvent::Tie,
+        "player_surrender" => OwnedEvent::PlayerSurrender,
+        "player_even_money" => OwnedEvent::PlayerEvenMoney,
+        "insurance_win" => OwnedEvent::InsuranceWin,
+        "insurance_loose" => OwnedEvent::InsuranceLoose,
+        "bankroll" => OwnedEvent::Bankroll(words.next()?.parse().ok()?),
+        "reshuffle" => OwnedEvent::Reshuffle,
+        "player_hand" => OwnedEvent::PlayerHand(parse_cards(&mut words)?),
+        "dealer_hand" => OwnedEvent::DealerHand(parse_cards(&mut words)?),
+        "dealer_upcard" => OwnedEvent::DealerUpcard(words.next()?.parse().ok()?),
+        "dealer_reveal" => OwnedEvent::DealerReveal(parse_cards(&mut words)?),
+        _ => return None,
+    };
+    words.next().is_none().then_some(event)
+}
+
+impl Display for History {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", write_rules(&self.rules))?;
+        writeln!(f, "bankroll {}", self.bankroll)?;
+        writeln!(f, "cards {}", write_cards(&self.cards))?;
+        self.steps
+            .iter()
+            .try_for_each(|step| writeln!(f, "{}", write_step(step)))
+    }
+}
+
+impl FromStr for History {
+    type Err = ParseHistoryError;
+
+    /// Read a history written out by its `Display`. Blank lines, and lines starting with "#",
+    /// are skipped.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut history = History {
+            rules: Rules::default(),
+            bankroll: 0,
+            cards: Vec::new(),
+            steps: Vec::new(),
+        };
+        for line in s.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut words = line.split_whitespace();
+            let steps = &mut history.steps;
+            let parsed = match words.next() {
+                Some("rules") => parse_rules(words).map(|r| history.rules = r),
+                Some("bankroll") => parse_one(words).map(|b| history.bankroll = b),
+                Some("cards") => parse_cards(words).map(|c| history.cards = c),
+                Some("wager") => parse_one(words).map(|w| steps.push(Step::Wager(w))),
+                Some("action") => parse_one(words)
+                    .and_then(|a: String| {
+                        ACTIONS.into_iter().find(|action| action_name(*action) == a)
+                    })
+                    .map(|a| steps.push(Step::Action(a))),
+                Some("insurance") => parse_one(words).map(|i| steps.push(Step::Insurance(i))),
+                Some("even_money") => parse_one(words).map(|e| steps.push(Step::EvenMoney(e))),
+                Some("event") => parse_event(words).map(|e| steps.push(Step::Event(e))),
+                _ => None,
+            };
+            parsed.ok_or_else(|| ParseHistoryError(line.to_string()))?;
+        }
+        Ok(history)
+    }
+}
@@ -1,4 +1,4 @@
-/// Implementation of single deck blackjack.
+/// Implementation of blackjack.
 pub mod blackjack;
 
 /// Functionality related to playing cards.
@@ -7,5 +7,26 @@ pub mod card;
 /// Functionality related to hands of playing cards.
 pub mod hand;
 
+/// Ways of writing out playing cards.
+pub mod style;
+
 /// Functionality related to decks of playing cards.
 pub mod deck;
+
+/// Functionality related to multi-deck dealing shoes.
+pub mod shoe;
+
+/// House rules for a game of blackjack.
+pub mod rules;
+
+/// Functionality related to the player's money.
+pub mod bankroll;
+
+/// Recording and replaying the history of a round.
+pub mod history;
+
+/// Exact chances of the dealer's final hand.
+pub mod dealer;
+
+/// Basic strategy, computed for a set of house rules.
+pub mod strategy;
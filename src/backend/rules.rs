@@ -0,0 +1,83 @@
+/// Two card totals the player may double down on.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Double {
+    Any,
+    NineToEleven,
+    TenToEleven,
+}
+
+/// When the player may give up half their wager instead of playing their hand.
+/// Late surrender is only offered once the dealer has peeked for a natural, early surrender before.
+/// If the dealer doesn't peek, a late surrender still loses the whole wager to a natural.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Surrender {
+    NotAllowed,
+    Late,
+    Early,
+}
+
+/// How much a natural pays, relative to the wager.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Payout {
+    ThreeToTwo,
+    SixToFive,
+    OneToOne,
+}
+
+impl Payout {
+    /// Get the winnings paid on a natural for a wager, rounded down to whole chips.
+    pub fn pay(&self, wager: u32) -> u32 {
+        match self {
+            Payout::ThreeToTwo => wager * 3 / 2,
+            Payout::SixToFive => wager * 6 / 5,
+            Payout::OneToOne => wager,
+        }
+    }
+}
+
+/// House rules for a game of blackjack.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Rules {
+    /// Whether the dealer hits a soft 17 (H17), or stands on all 17s (S17).
+    pub dealer_hits_soft_17: bool,
+    pub decks: u8,
+    /// The table minimum and maximum wagers.
+    pub min_bet: u32,
+    pub max_bet: u32,
+    pub payout: Payout,
+    pub double: Double,
+    pub double_after_split: bool,
+    /// The most hands the player may hold by splitting and re-splitting.
+    pub max_hands: usize,
+    pub resplit_aces: bool,
+    pub hit_split_aces: bool,
+    pub surrender: Surrender,
+    /// Whether the dealer peeks at their hole card for a natural when showing an ace or ten.
+    pub peek: bool,
+}
+
+impl Rules {
+    /// Check if the dealer must hit a hand with these points.
+    pub fn is_dealer_hitting(&self, points: u8, is_soft: bool) -> bool {
+        points < 17 || self.dealer_hits_soft_17 && is_soft && points == 17
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules {
+            dealer_hits_soft_17: true,
+            decks: 1,
+            min_bet: 10,
+            max_bet: 500,
+            payout: Payout::ThreeToTwo,
+            double: Double::Any,
+            double_after_split: true,
+            max_hands: 4,
+            resplit_aces: false,
+            hit_split_aces: false,
+            surrender: Surrender::NotAllowed,
+            peek: true,
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::backend::card::Card;
+use crate::backend::deck::Deck;
+use crate::behaviour::draw::DrawFrom;
+use crate::behaviour::reshuffle::Reshuffle;
+use rand::{thread_rng, Rng};
+
+/// Represents a dealing shoe, holding several shuffled decks of playing cards.
+/// A cut card is placed after a fraction of the cards, its penetration, after which a reshuffle
+/// is due.
+#[derive(Debug)]
+pub struct Shoe {
+    deck: Deck,
+}
+
+impl Shoe {
+    /// Build a freshly shuffled shoe of a number of decks, with the cut card at a penetration
+    /// between 0 and 1.
+    /// Panics if the shoe holds no decks, as there would be no cards to deal.
+    pub fn new(decks: u8, penetration: f64) -> Shoe {
+        Shoe::with_rng(decks, penetration, &mut thread_rng())
+    }
+
+    /// Build a shoe of a number of decks, shuffled with a seed drawn from a random number
+    /// generator.
+    pub fn with_rng<R: Rng + ?Sized>(decks: u8, penetration: f64, rng: &mut R) -> Shoe {
+        Shoe::with_seed(decks, penetration, rng.gen())
+    }
+
+    /// Build a shoe of a number of decks, shuffled from a seed.
+    /// Shoes built from the same seed are dealt in the same order. Penetrations outside 0 to 1
+    /// are clamped to that range.
+    pub fn with_seed(decks: u8, penetration: f64, seed: u64) -> Shoe {
+        assert!(decks > 0, "a shoe needs at least one deck");
+        Shoe {
+            deck: Deck::of_decks(decks, seed).with_penetration(penetration),
+        }
+    }
+
+    /// Get the seed used for the shoe's current shuffle.
+    pub fn seed(&self) -> u64 {
+        self.deck.seed()
+    }
+
+    /// Get the number of cards in the full shoe.
+    pub fn size(&self) -> usize {
+        self.deck.size()
+    }
+
+    /// Get the number of cards left to be dealt.
+    pub fn remaining(&self) -> usize {
+        self.deck.remaining()
+    }
+
+    /// Get the number of cards dealt before the cut card is reached.
+    pub fn cut_card(&self) -> usize {
+        self.deck.cut_card()
+    }
+
+    /// Get the fraction of the shoe dealt since it was last shuffled.
+    pub fn penetration(&self) -> f64 {
+        self.deck.penetration()
+    }
+}
+
+impl DrawFrom<Card> for Shoe {
+    fn draw(&mut self) -> Option<Card> {
+        self.deck.draw()
+    }
+
+    fn reshuffle_discards(&mut self, in_play: &[&Card]) {
+        self.deck.reshuffle_discards(in_play)
+    }
+}
+
+impl Reshuffle for Shoe {
+    fn needs_reshuffle(&self) -> bool {
+        self.deck.needs_reshuffle()
+    }
+
+    fn reshuffle(&mut self) {
+        self.deck.reshuffle()
+    }
+}
@@ -0,0 +1,638 @@
+use crate::backend::card::Rank;
+use crate::backend::dealer::{Composition, Outcomes};
+use crate::backend::rules::{Double, Rules, Surrender};
+use crate::behaviour::interface::Action;
+use std::fmt;
+use std::str::FromStr;
+
+/// How a hand is played against an upcard, as a cell of a basic strategy chart.
+/// Doubling, splitting and surrendering aren't always offered, so those plays say what to do
+/// instead.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Play {
+    Hit,
+    Stand,
+    /// Double if allowed, otherwise hit.
+    DoubleOrHit,
+    /// Double if allowed, otherwise stand.
+    DoubleOrStand,
+    /// Split if allowed, otherwise play the hand by its total.
+    Split,
+    /// Surrender if allowed, otherwise hit.
+    SurrenderOrHit,
+    /// Surrender if allowed, otherwise stand.
+    SurrenderOrStand,
+    /// Surrender if allowed, otherwise split.
+    SurrenderOrSplit,
+}
+
+/// Error used when a strategy chart can't be read, naming the line it's on, counting from one.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ParseStrategyError {
+    /// A table's header isn't its name followed by the upcards 2 to 10 then A.
+    InvalidHeader(usize),
+    /// A row isn't a total or pair of the table it's in, or is given twice.
+    InvalidRow(usize, String),
+    /// A row doesn't have a cell for each upcard.
+    WrongLength(usize),
+    /// A cell isn't a play allowed in its table, such as a split of a hard total.
+    InvalidCell(usize, String),
+    /// A table is missing a row, by the table's name and the row's label.
+    MissingRow(String, String),
+}
+
+/// The lowest hard total, and the number of hard totals, in a chart.
+const HARD: (u8, usize) = (4, 18);
+
+/// The lowest soft total, and the number of soft totals, in a chart.
+const SOFT: (u8, usize) = (12, 10);
+
+/// The names of the hard, soft and pair tables in a chart.
+const TABLES: [&str; 3] = ["hard", "soft", "pairs"];
+
+/// Index of the pair table in `TABLES`.
+const PAIRS: usize = 2;
+
+/// Ranks by their points, with an ace as one, and every ten valued card as a ten.
+const VALUES: [Rank; 10] = [
+    Rank::Ace,
+    Rank::Two,
+    Rank::Three,
+    Rank::Four,
+    Rank::Five,
+    Rank::Six,
+    Rank::Seven,
+    Rank::Eight,
+    Rank::Nine,
+    Rank::Ten,
+];
+
+/// Basic strategy for a set of house rules, as a chart of plays for hard totals, soft totals and
+/// pairs, against each dealer upcard.
+///
+/// The chart is computed rather than written out, by the expected value of each play. The
+/// dealer's chances come from the full shoe less their upcard and the player's two cards, drawn
+/// without replacement, under the rules for soft 17 and peeking. The player's hits are drawn
+/// from that same shoe. A hard total is played by the average over the two card hands making
+/// it, weighted by their chances of being dealt. Split hands are played out without
+/// re-splitting, doubling only if allowed after a split.
+///
+/// A chart can also be written out and read back in as text, with one table each for hard
+/// totals 4 to 21, soft totals 12 to 21 and pairs of 2 to 10 then A. Each table starts with a
+/// header of its name and the upcards, then has a row for each hand. Cells are separated by
+/// commas or whitespace, and `#` starts a comment:
+///
+/// ```text
+/// hard,2,3,4,5,6,7,8,9,10,A
+/// 4,H,H,H,H,H,H,H,H,H,H
+/// ...
+/// 16,S,S,S,S,S,H,H,Rh,Rh,Rh
+/// ```
+///
+/// The plays are H to hit, S to stand, D to double or else hit, Ds to double or else stand, P to
+/// split, and Rh, Rs or Rp to surrender or else hit, stand or split. Splits are only allowed in
+/// the pair table.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Strategy {
+    hard: [[Play; 10]; HARD.1],
+    soft: [[Play; 10]; SOFT.1],
+    pairs: [[Play; 10]; 10],
+}
+
+impl Strategy {
+    /// Compute basic strategy for a set of house rules.
+    /// Panics if the rules deal from no decks, as there are no chances to work out.
+    pub fn new(rules: Rules) -> Strategy {
+        assert!(rules.decks > 0, "a strategy needs at least one deck");
+        let mut strategy = Strategy {
+            hard: [[Play::Stand; 10]; HARD.1],
+            soft: [[Play::Stand; 10]; SOFT.1],
+            pairs: [[Play::Stand; 10]; 10],
+        };
+        for upcard in VALUES {
+            let up = column(upcard);
+            for (row, total) in (HARD.0..).take(HARD.1).enumerate() {
+                strategy.hard[row][up] =
+                    best_play(&rules, upcard, total, &hard_hands(total), false);
+            }
+            for (row, total) in (SOFT.0..).take(SOFT.1).enumerate() {
+                let hand = [Rank::Ace, VALUES[(total - 12) as usize]];
+                strategy.soft[row][up] = best_play(&rules, upcard, total - 10, &[hand], false);
+            }
+            for rank in VALUES {
+                strategy.pairs[column(rank)][up] =
+                    best_play(&rules, upcard, value(rank) * 2, &[[rank, rank]], true);
+            }
+        }
+        strategy
+    }
+
+    /// Get the play for a hand of cards, by their ranks, against the dealer's upcard.
+    pub fn play(&self, cards: &[Rank], upcard: Rank) -> Play {
+        let up = column(upcard);
+        match cards {
+            [first, second] if first == second => self.pairs[column(*first)][up],
+            _ => {
+                let (hard, soft) = total(cards);
+                self.total_play(hard, soft, up)
+            }
+        }
+    }
+
+    /// Get the advised action for a hand of cards, by their ranks, against the dealer's upcard,
+    /// out of the actions currently offered.
+    pub fn advise(&self, cards: &[Rank], upcard: Rank, actions: &[Action]) -> Action {
+        let (hard, soft) = total(cards);
+        let by_total = || self.total_play(hard, soft, column(upcard));
+        let action = resolve(self.play(cards, upcard), actions, by_total);
+        if actions.contains(&action) {
+            action
+        } else {
+            Action::PlayerStay
+        }
+    }
+
+    /// Get the hard, soft and pair tables.
+    fn tables(&self) -> [&[[Play; 10]]; 3] {
+        [&self.hard, &self.soft, &self.pairs]
+    }
+
+    /// Get the hard, soft and pair tables to fill in.
+    fn tables_mut(&mut self) -> [&mut [[Play; 10]]; 3] {
+        [&mut self.hard, &mut self.soft, &mut self.pairs]
+    }
+
+    /// Get the play for a hand by its hard total, and whether it holds an ace.
+    fn total_play(&self, hard: u8, soft: bool, up: usize) -> Play {
+        let points = points(hard, soft);
+        if points > 21 {
+            Play::Stand
+        } else if soft && hard + 10 == points {
+            self.soft[(points - SOFT.0) as usize][up]
+        } else {
+            self.hard[points.saturating_sub(HARD.0) as usize][up]
+        }
+    }
+}
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (table, rows) in self.tables().into_iter().enumerate() {
+            if table > 0 {
+                writeln!(f)?;
+            }
+            let upcards: Vec<String> = (0..10).map(upcard_label).collect();
+            writeln!(f, "{},{}", TABLES[table], upcards.join(","))?;
+            for (row, plays) in rows.iter().enumerate() {
+                let cells: Vec<&str> = plays.iter().map(|play| code(*play)).collect();
+                writeln!(f, "{},{}", row_label(table, row), cells.join(","))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Strategy {
+    type Err = ParseStrategyError;
+
+    /// Parse a strategy chart, with every row of its hard, soft and pair tables.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut strategy = Strategy {
+            hard: [[Play::Stand; 10]; HARD.1],
+            soft: [[Play::Stand; 10]; SOFT.1],
+            pairs: [[Play::Stand; 10]; 10],
+        };
+        let mut seen = [[false; HARD.1]; 3];
+        let mut table = None;
+        for (index, line) in s.lines().enumerate() {
+            let number = index + 1;
+            let cells: Vec<&str> = line
+                .split('#')
+                .next()
+                .unwrap_or_default()
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|cell| !cell.is_empty())
+                .collect();
+            let Some((label, cells)) = cells.split_first() else {
+                continue;
+            };
+            if let Some(name) = TABLES.iter().position(|n| n.eq_ignore_ascii_case(label)) {
+                let upcards = cells.len() == 10
+                    && cells
+                        .iter()
+                        .enumerate()
+                        .all(|(up, cell)| cell.parse().map(column) == Ok(up));
+                if !upcards {
+                    return Err(ParseStrategyError::InvalidHeader(number));
+                }
+                table = Some(name);
+                continue;
+            }
+            let invalid_row = || ParseStrategyError::InvalidRow(number, label.to_string());
+            let table = table.ok_or_else(invalid_row)?;
+            let row = parse_row(table, label)
+                .filter(|row| !seen[table][*row])
+                .ok_or_else(invalid_row)?;
+            if cells.len() != 10 {
+                return Err(ParseStrategyError::WrongLength(number));
+            }
+            for (up, cell) in cells.iter().enumerate() {
+                let play = parse_play(cell)
+                    .filter(|play| {
+                        table == PAIRS || !matches!(play, Play::Split | Play::SurrenderOrSplit)
+                    })
+                    .ok_or_else(|| ParseStrategyError::InvalidCell(number, cell.to_string()))?;
+                strategy.tables_mut()[table][row][up] = play;
+            }
+            seen[table][row] = true;
+        }
+        for (table, rows) in strategy.tables().into_iter().enumerate() {
+            if let Some(row) = (0..rows.len()).find(|row| !seen[table][*row]) {
+                return Err(ParseStrategyError::MissingRow(
+                    TABLES[table].to_string(),
+                    row_label(table, row),
+                ));
+            }
+        }
+        Ok(strategy)
+    }
+}
+
+/// The code for a play in a written out chart.
+fn code(play: Play) -> &'static str {
+    match play {
+        Play::Hit => "H",
+        Play::Stand => "S",
+        Play::DoubleOrHit => "D",
+        Play::DoubleOrStand => "Ds",
+        Play::Split => "P",
+        Play::SurrenderOrHit => "Rh",
+        Play::SurrenderOrStand => "Rs",
+        Play::SurrenderOrSplit => "Rp",
+    }
+}
+
+/// Parse the code for a play, ignoring case.
+fn parse_play(cell: &str) -> Option<Play> {
+    [
+        Play::Hit,
+        Play::Stand,
+        Play::DoubleOrHit,
+        Play::DoubleOrStand,
+        Play::Split,
+        Play::SurrenderOrHit,
+        Play::SurrenderOrStand,
+        Play::SurrenderOrSplit,
+    ]
+    .into_iter()
+    .find(|play| code(*play).eq_ignore_ascii_case(cell))
+}
+
+/// The label of an upcard's column, or a pair's row, from 2 to 10 then A.
+fn upcard_label(column: usize) -> String {
+    match column {
+        9 => String::from("A"),
+        column => (column + 2).to_string(),
+    }
+}
+
+/// The label of a row in a table, its total or the rank of its pair.
+fn row_label(table: usize, row: usize) -> String {
+    match table {
+        0 => (HARD.0 as usize + row).to_string(),
+        1 => (SOFT.0 as usize + row).to_string(),
+        _ => upcard_label(row),
+    }
+}
+
+/// Parse the label of a row in a table to its index.
+fn parse_row(table: usize, label: &str) -> Option<usize> {
+    let total = |(lowest, rows): (u8, usize)| {
+        let total: u8 = label.parse().ok()?;
+        let row = total.checked_sub(lowest)? as usize;
+        (row < rows).then_some(row)
+    };
+    match table {
+        0 => total(HARD),
+        1 => total(SOFT),
+        _ => label.parse().ok().map(column),
+    }
+}
+
+/// Turn a play into an action, out of the actions offered, falling back on the play for the
+/// hand's total when a split isn't offered.
+fn resolve(play: Play, actions: &[Action], by_total: impl Fn() -> Play) -> Action {
+    let offered = |action| actions.contains(&action);
+    match play {
+        Play::Hit => Action::PlayerHit,
+        Play::Stand => Action::PlayerStay,
+        Play::DoubleOrHit if offered(Action::PlayerDouble) => Action::PlayerDouble,
+        Play::DoubleOrHit => Action::PlayerHit,
+        Play::DoubleOrStand if offered(Action::PlayerDouble) => Action::PlayerDouble,
+        Play::DoubleOrStand => Action::PlayerStay,
+        Play::Split if offered(Action::PlayerSplit) => Action::PlayerSplit,
+        Play::Split => resolve(by_total(), actions, by_total),
+        Play::SurrenderOrHit | Play::SurrenderOrStand | Play::SurrenderOrSplit
+            if offered(Action::PlayerSurrender) =>
+        {
+            Action::PlayerSurrender
+        }
+        Play::SurrenderOrHit => Action::PlayerHit,
+        Play::SurrenderOrStand => Action::PlayerStay,
+        Play::SurrenderOrSplit => resolve(Play::Split, actions, by_total),
+    }
+}
+
+/// Get the chart column of an upcard, from two to ten then ace.
+fn column(rank: Rank) -> usize {
+    match value(rank) {
+        1 => 9,
+        value => value as usize - 2,
+    }
+}
+
+/// Get the points of a rank, with an ace as one.
+fn value(rank: Rank) -> u8 {
+    match rank {
+        Rank::Ace => 1,
+        Rank::Two => 2,
+        Rank::Three => 3,
+        Rank::Four => 4,
+        Rank::Five => 5,
+        Rank::Six => 6,
+        Rank::Seven => 7,
+        Rank::Eight => 8,
+        Rank::Nine => 9,
+        Rank::Ten | Rank::Jack | Rank::Queen | Rank::King => 10,
+    }
+}
+
+/// Get the hard total of cards, counting aces as one, and whether they hold an ace.
+fn total(cards: &[Rank]) -> (u8, bool) {
+    cards.iter().fold((0, false), |(hard, soft), rank| {
+        (hard + value(*rank), soft || *rank == Rank::Ace)
+    })
+}
+
+/// Get the points of a hard total, counting an ace as eleven if it doesn't bust.
+fn points(hard: u8, ace: bool) -> u8 {
+    if ace && hard + 10 <= 21 {
+        hard + 10
+    } else {
+        hard
+    }
+}
+
+/// Two card hands making a hard total without an ace, leaving out pairs unless there is no
+/// other way to make it.
+fn hard_hands(total: u8) -> Vec<[Rank; 2]> {
+    let deals = |pairs: bool| -> Vec<[Rank; 2]> {
+        (2..=10)
+            .flat_map(|first| (first..=10).map(move |second| (first, second)))
+            .filter(|(first, second)| first + second == total && (first == second) == pairs)
+            .map(|(first, second)| [VALUES[first as usize - 1], VALUES[second as usize - 1]])
+            .collect()
+    };
+    let hands = deals(false);
+    if hands.is_empty() {
+        deals(true)
+    } else {
+        hands
+    }
+}
+
+/// Get the best play for a two card hand against an upcard, by its hard total and the cards it
+/// could be dealt as. The expected values of each play are averaged over those cards, weighted
+/// by their chances of being dealt. Without any cards, as for a hard 21, the hand is played from
+/// the shoe less just the upcard. A pair may be split if it is played as one.
+fn best_play(rules: &Rules, upcard: Rank, hard: u8, hands: &[[Rank; 2]], pair: bool) -> Play {
+    let mut shoe = Composition::with_decks(rules.decks);
+    shoe.remove(upcard);
+    let ace = hands.iter().flatten().any(|rank| *rank == Rank::Ace);
+    let dealt: Vec<(&[Rank], f64)> = match hands {
+        [] => vec![(&[], 1.0)],
+        hands => hands
+            .iter()
+            .map(|hand| (&hand[..], deal_chance(&shoe, *hand)))
+            .collect(),
+    };
+    let weights: f64 = dealt.iter().map(|(_, chance)| chance).sum();
+    let mut plays = Plays::default();
+    for (cards, chance) in dealt {
+        let odds = Odds::new(rules, upcard, cards);
+        let split = pair.then(|| odds.split(rules, hard / 2)).flatten();
+        plays.add(&odds.plays(rules, hard, ace, split), chance / weights);
+    }
+    plays.best(rules)
+}
+
+/// Get the chance of being dealt two cards, in either order, from a shoe.
+fn deal_chance(shoe: &Composition, [first, second]: [Rank; 2]) -> f64 {
+    let count = |rank| shoe.count(rank) as f64;
+    if first == second {
+        count(first) * (count(second) - 1.0)
+    } else {
+        2.0 * count(first) * count(second)
+    }
+}
+
+/// Expected values of the plays for a two card hand, per unit wagered, where allowed.
+#[derive(Debug, Default)]
+struct Plays {
+    stand: f64,
+    hit: f64,
+    double: Option<f64>,
+    split: Option<f64>,
+    /// Chance the dealer has a natural, settled before the player acts if they peek.
+    natural: f64,
+    /// Whether the dealer peeks for a natural with this upcard.
+    peeked: bool,
+}
+
+impl Plays {
+    /// Add in the expected values of the plays for one way to deal a hand, weighted by its share
+    /// of the chances.
+    fn add(&mut self, plays: &Plays, weight: f64) {
+        let add = |sum: Option<f64>, ev: Option<f64>| Some(sum.unwrap_or(0.0) + weight * ev?);
+        self.stand += weight * plays.stand;
+        self.hit += weight * plays.hit;
+        self.double = add(self.double, plays.double);
+        self.split = add(self.split, plays.split);
+        self.natural += weight * plays.natural;
+        self.peeked = plays.peeked;
+    }
+
+    /// Get the play with the best expected value.
+    fn best(&self, rules: &Rules) -> Play {
+        let (fallback, mut ev) = if self.hit > self.stand {
+            (Play::Hit, self.hit)
+        } else {
+            (Play::Stand, self.stand)
+        };
+        let mut play = fallback;
+        if let Some(double) = self.double.filter(|double| *double > ev) {
+            play = match fallback {
+                Play::Hit => Play::DoubleOrHit,
+                _ => Play::DoubleOrStand,
+            };
+            ev = double;
+        }
+        if let Some(split) = self.split.filter(|split| *split > ev) {
+            play = Play::Split;
+            ev = split;
+        }
+        if !self.surrender(rules, ev) {
+            play
+        } else if play == Play::Split {
+            Play::SurrenderOrSplit
+        } else if fallback == Play::Hit {
+            Play::SurrenderOrHit
+        } else {
+            Play::SurrenderOrStand
+        }
+    }
+
+    /// Check if surrendering beats playing on, for the expected value of the best other play.
+    /// Early surrender comes before the dealer peeks, so also saves on their naturals. Late
+    /// surrender without a peek saves nothing on them.
+    fn surrender(&self, rules: &Rules, ev: f64) -> bool {
+        match rules.surrender {
+            Surrender::NotAllowed => false,
+            Surrender::Early if self.peeked => -0.5 > -self.natural + (1.0 - self.natural) * ev,
+            Surrender::Late if !self.peeked => -0.5 * (1.0 + self.natural) > ev,
+            _ => -0.5 > ev,
+        }
+    }
+}
+
+/// The chances used to play a hand against one dealer upcard.
+struct Odds {
+    /// Chance of drawing each value, with an ace at zero and tens at nine.
+    draw: [f64; 10],
+    /// Chances of the dealer's final hands, given play continues past any peek.
+    dealer: Outcomes,
+    /// Chance the dealer has a natural, settled before the player acts if they peek.
+    natural: f64,
+    /// Whether the dealer peeks for a natural with this upcard.
+    peeked: bool,
+    /// Expected value of hitting or standing, whichever is best, by hard total and holding an
+    /// ace.
+    best: [[f64; 2]; 22],
+}
+
+impl Odds {
+    /// Work out the chances against an upcard, from a full shoe less that card and the player's
+    /// cards.
+    fn new(rules: &Rules, upcard: Rank, cards: &[Rank]) -> Odds {
+        let mut shoe = Composition::with_decks(rules.decks);
+        shoe.remove(upcard);
+        for card in cards {
+            shoe.remove(*card);
+        }
+        let draw = VALUES.map(|rank| shoe.chance(rank));
+        let dealer = Outcomes::new(rules, &shoe, upcard);
+        let natural = dealer.natural();
+        let peeked = rules.peek && (value(upcard) == 1 || value(upcard) == 10);
+        let dealer = if peeked {
+            dealer.without_natural()
+        } else {
+            dealer
+        };
+        let mut odds = Odds {
+            draw,
+            dealer,
+            natural,
+            peeked,
+            best: [[0.0; 2]; 22],
+        };
+        for hard in (1..=21).rev() {
+            for ace in [false, true] {
+                let stand = odds.stand(points(hard, ace));
+                odds.best[hard as usize][ace as usize] = if points(hard, ace) == 21 {
+                    stand
+                } else {
+                    stand.max(odds.draw_then(hard, ace, |h, a| odds.hit_or_stand(h, a)))
+                };
+            }
+        }
+        odds
+    }
+
+    /// Expected value of standing on points, per unit wagered.
+    fn stand(&self, points: u8) -> f64 {
+        if points > 21 {
+            return -1.0;
+        }
+        let mut ev = self.dealer.bust() - self.dealer.natural();
+        for final_points in 17..=21 {
+            let chance = self.dealer.points(final_points);
+            ev += match points.cmp(&final_points) {
+                std::cmp::Ordering::Greater => chance,
+                std::cmp::Ordering::Less => -chance,
+                std::cmp::Ordering::Equal => 0.0,
+            };
+        }
+        ev
+    }
+
+    /// Expected value of drawing one more card to a hand, then playing `then` on it.
+    fn draw_then(&self, hard: u8, ace: bool, then: impl Fn(u8, bool) -> f64) -> f64 {
+        (1..=10)
+            .zip(self.draw)
+            .map(|(value, chance)| chance * then(hard + value, ace || value == 1))
+            .sum()
+    }
+
+    /// Expected value of hitting or standing a hand, whichever is best.
+    fn hit_or_stand(&self, hard: u8, ace: bool) -> f64 {
+        match self.best.get(hard as usize) {
+            Some(best) => best[ace as usize],
+            None => -1.0,
+        }
+    }
+
+    /// Expected value of doubling on a hand.
+    fn double(&self, hard: u8, ace: bool) -> f64 {
+        2.0 * self.draw_then(hard, ace, |h, a| self.stand(points(h, a)))
+    }
+
+    /// Expected values of standing, hitting and doubling a two card hand, where allowed.
+    fn values(&self, rules: &Rules, hard: u8, ace: bool, split: bool) -> (f64, f64, Option<f64>) {
+        let stand = self.stand(points(hard, ace));
+        let hit = self.draw_then(hard, ace, |h, a| self.hit_or_stand(h, a));
+        let doubling = match rules.double {
+            Double::Any => true,
+            Double::NineToEleven => (9..=11).contains(&points(hard, ace)),
+            Double::TenToEleven => (10..=11).contains(&points(hard, ace)),
+        };
+        let double =
+            (doubling && (!split || rules.double_after_split)).then(|| self.double(hard, ace));
+        (stand, hit, double)
+    }
+
+    /// Get the expected values of the plays for a two card hand, given the expected value of
+    /// splitting it if it is a pair.
+    fn plays(&self, rules: &Rules, hard: u8, ace: bool, split: Option<f64>) -> Plays {
+        let (stand, hit, double) = self.values(rules, hard, ace, false);
+        Plays {
+            stand,
+            hit,
+            double,
+            split,
+            natural: self.natural,
+            peeked: self.peeked,
+        }
+    }
+
+    /// Get the expected value of splitting a pair of cards of a value, if allowed.
+    fn split(&self, rules: &Rules, value: u8) -> Option<f64> {
+        let split = self.draw_then(value, value == 1, |hard, ace| {
+            if value == 1 && !rules.hit_split_aces {
+                return self.stand(points(hard, ace));
+            }
+            let (stand, hit, double) = self.values(rules, hard, ace, true);
+            stand.max(hit).max(double.unwrap_or(f64::MIN))
+        });
+        (rules.max_hands >= 2).then_some(2.0 * split)
+    }
+}
@@ -0,0 +1,154 @@
+use crate::backend::card::{Rank, Suit};
+use crate::behaviour::card_like::CardLike;
+use std::fmt::{Display, Formatter};
+
+/// Ways of writing out playing cards.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Style {
+    /// Full names, such as "Ten of Hearts".
+    Long,
+    /// Rank and suit initials, such as "TH".
+    Short,
+    /// Rank and suit symbol, such as "10♥".
+    Symbol,
+    /// Unicode playing card glyphs, such as "🂺".
+    Glyph,
+    /// Multi-line ASCII-art card boxes, laid out side by side.
+    Art,
+}
+
+/// Wrapper which displays a card, or a slice of cards, in a style.
+/// A hand of cards can be styled through its iterator, as `Styled(hand.iter().as_slice(), style)`.
+#[derive(Debug)]
+pub struct Styled<'a, T: ?Sized>(pub &'a T, pub Style);
+
+impl<C> Display for Styled<'_, C>
+where
+    C: CardLike,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (rank, suit) = (self.0.get_rank(), self.0.get_suit());
+        match self.1 {
+            Style::Long => write!(f, "{:?} of {:?}s", rank, suit),
+            Style::Short => write!(f, "{}{}", short_rank(rank), short_suit(suit)),
+            Style::Symbol => write!(f, "{}{}", symbol_rank(rank), symbol_suit(suit)),
+            Style::Glyph => write!(f, "{}", glyph(rank, suit)),
+            Style::Art => write!(f, "{}", art(rank, suit).join("\n")),
+        }
+    }
+}
+
+impl<C> Display for Styled<'_, [C]>
+where
+    C: CardLike,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let cards = self.0.iter().map(|c| Styled(c, self.1).to_string());
+        let result = match self.1 {
+            Style::Long => cards.collect::<Vec<String>>().join(", "),
+            Style::Short | Style::Symbol | Style::Glyph => cards.collect::<Vec<String>>().join(" "),
+            Style::Art if self.0.is_empty() => String::new(),
+            Style::Art => {
+                let boxes: Vec<[String; 5]> = self
+                    .0
+                    .iter()
+                    .map(|c| art(c.get_rank(), c.get_suit()))
+                    .collect();
+                (0..5)
+                    .map(|line| {
+                        let line: Vec<&str> = boxes.iter().map(|b| b[line].as_str()).collect();
+                        line.join(" ")
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            }
+        };
+        write!(f, "{}", result)
+    }
+}
+
+/// Get the single character initial of a rank, where ten is written as "T".
+fn short_rank(rank: Rank) -> &'static str {
+    match rank {
+        Rank::Ten => "T",
+        rank => symbol_rank(rank),
+    }
+}
+
+/// Get how a rank is written in the corner of a card.
+fn symbol_rank(rank: Rank) -> &'static str {
+    match rank {
+        Rank::Ace => "A",
+        Rank::Two => "2",
+        Rank::Three => "3",
+        Rank::Four => "4",
+        Rank::Five => "5",
+        Rank::Six => "6",
+        Rank::Seven => "7",
+        Rank::Eight => "8",
+        Rank::Nine => "9",
+        Rank::Ten => "10",
+        Rank::Jack => "J",
+        Rank::Queen => "Q",
+        Rank::King => "K",
+    }
+}
+
+/// Get the initial of a suit.
+fn short_suit(suit: Suit) -> char {
+    match suit {
+        Suit::Spade => 'S',
+        Suit::Heart => 'H',
+        Suit::Club => 'C',
+        Suit::Diamond => 'D',
+    }
+}
+
+/// Get the Unicode symbol of a suit.
+fn symbol_suit(suit: Suit) -> char {
+    match suit {
+        Suit::Spade => '♠',
+        Suit::Heart => '♥',
+        Suit::Club => '♣',
+        Suit::Diamond => '♦',
+    }
+}
+
+/// Get the Unicode playing card glyph of a card, from the block starting at U+1F0A0.
+/// The block has a knight between the jack and queen, which isn't used.
+fn glyph(rank: Rank, suit: Suit) -> char {
+    let suit = match suit {
+        Suit::Spade => 0x1F0A0,
+        Suit::Heart => 0x1F0B0,
+        Suit::Diamond => 0x1F0C0,
+        Suit::Club => 0x1F0D0,
+    };
+    let rank = match rank {
+        Rank::Ace => 0x1,
+        Rank::Two => 0x2,
+        Rank::Three => 0x3,
+        Rank::Four => 0x4,
+        Rank::Five => 0x5,
+        Rank::Six => 0x6,
+        Rank::Seven => 0x7,
+        Rank::Eight => 0x8,
+        Rank::Nine => 0x9,
+        Rank::Ten => 0xA,
+        Rank::Jack => 0xB,
+        Rank::Queen => 0xD,
+        Rank::King => 0xE,
+    };
+    char::from_u32(suit + rank).unwrap()
+}
+
+/// Get the lines of an ASCII-art box for a card.
+fn art(rank: Rank, suit: Suit) -> [String; 5] {
+    let rank = symbol_rank(rank);
+    [
+        "+-----+".to_string(),
+        format!("|{:<5}|", rank),
+        format!("|  {}  |", symbol_suit(suit)),
+        format!("|{:>5}|", rank),
+        "+-----+".to_string(),
+    ]
+}
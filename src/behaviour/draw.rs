@@ -13,6 +13,12 @@ where
 {
     /// Draw a card from this collection.
     fn draw(&mut self) -> Option<C>;
+
+    /// Shuffle the discards back in, once this collection has run out part way through a round.
+    /// Every card is gathered up except those still in play. Collections which can't be
+    /// reshuffled are left as they are.
+    #[allow(clippy::needless_lifetimes)] // Mocking needs the lifetime named.
+    fn reshuffle_discards<'a>(&mut self, _in_play: &[&'a C]) {}
 }
 
 /// The ability to draw from a drawable collection of cards.
@@ -1,13 +1,29 @@
 use crate::behaviour::card_iter::CardIter;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize, Serializer};
 
 /// Actions that the user may take on their turn.
+/// With the serde feature, actions serialize by their verb, such as "hit".
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Action {
+    #[cfg_attr(feature = "serde", serde(rename = "stay"))]
     PlayerStay,
+    #[cfg_attr(feature = "serde", serde(rename = "hit"))]
     PlayerHit,
+    /// Double the wager, and take exactly one more card.
+    #[cfg_attr(feature = "serde", serde(rename = "double"))]
+    PlayerDouble,
+    /// Split a pair into two hands.
+    #[cfg_attr(feature = "serde", serde(rename = "split"))]
+    PlayerSplit,
+    /// Give up half the wager, instead of playing the hand.
+    #[cfg_attr(feature = "serde", serde(rename = "surrender"))]
+    PlayerSurrender,
 }
 
 /// Game events displayable to the user.
+/// With the serde feature, events serialize as their `OwnedEvent`, and deserialize as one.
 #[derive(Debug)]
 pub enum Event<'a, H>
 where
@@ -15,6 +31,10 @@ where
 {
     PlayerBust,
     PlayerBlackjack,
+    PlayerDouble,
+    PlayerSplit,
+    /// Following player events are about the player's hand at this index, after a split.
+    CurrentHand(usize),
     DealerBust,
     DealerBlackjack,
     DealerStay,
@@ -22,8 +42,111 @@ where
     PlayerWin,
     PlayerLoose,
     Tie,
+    PlayerSurrender,
+    /// The player was paid one to one for their natural, having taken even money.
+    PlayerEvenMoney,
+    InsuranceWin,
+    InsuranceLoose,
+    /// The player's balance, once a round has been settled.
+    Bankroll(u32),
+    /// The deck reached its cut card, and was reshuffled before the round.
+    Reshuffle,
     PlayerHand(&'a H),
     DealerHand(&'a H),
+    /// The dealer's hand while the hole card is face down, shown only by its upcard.
+    DealerUpcard(&'a H::Card),
+    /// The dealer's hand once the hole card has been turned over.
+    DealerReveal(&'a H),
+}
+
+/// A game event which owns its cards, such as one read back from a log.
+/// It mirrors `Event`, with each hand held as a list of cards.
+/// With the serde feature, events serialize tagged by name, such as
+/// `{"event":"player_hand","value":["5H","6D"]}` or `{"event":"dealer_hit"}`.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "event", content = "value", rename_all = "snake_case")
+)]
+pub enum OwnedEvent<C> {
+    PlayerBust,
+    PlayerBlackjack,
+    PlayerDouble,
+    PlayerSplit,
+    CurrentHand(usize),
+    DealerBust,
+    DealerBlackjack,
+    DealerStay,
+    DealerHit,
+    PlayerWin,
+    PlayerLoose,
+    Tie,
+    PlayerSurrender,
+    PlayerEvenMoney,
+    InsuranceWin,
+    InsuranceLoose,
+    Bankroll(u32),
+    Reshuffle,
+    PlayerHand(Vec<C>),
+    DealerHand(Vec<C>),
+    DealerUpcard(C),
+    DealerReveal(Vec<C>),
+}
+
+impl<C> OwnedEvent<C> {
+    /// Copy an event, turning each of the cards it shows into a card of this event.
+    pub fn from_event<'a, H>(event: &Event<'a, H>, mut card: impl FnMut(&'a H::Card) -> C) -> Self
+    where
+        H: CardIter,
+    {
+        let mut cards = |hand: &'a H| hand.iter().map(&mut card).collect();
+        match *event {
+            Event::PlayerBust => OwnedEvent::PlayerBust,
+            Event::PlayerBlackjack => OwnedEvent::PlayerBlackjack,
+            Event::PlayerDouble => OwnedEvent::PlayerDouble,
+            Event::PlayerSplit => OwnedEvent::PlayerSplit,
+            Event::CurrentHand(index) => OwnedEvent::CurrentHand(index),
+            Event::DealerBust => OwnedEvent::DealerBust,
+            Event::DealerBlackjack => OwnedEvent::DealerBlackjack,
+            Event::DealerStay => OwnedEvent::DealerStay,
+            Event::DealerHit => OwnedEvent::DealerHit,
+            Event::PlayerWin => OwnedEvent::PlayerWin,
+            Event::PlayerLoose => OwnedEvent::PlayerLoose,
+            Event::Tie => OwnedEvent::Tie,
+            Event::PlayerSurrender => OwnedEvent::PlayerSurrender,
+            Event::PlayerEvenMoney => OwnedEvent::PlayerEvenMoney,
+            Event::InsuranceWin => OwnedEvent::InsuranceWin,
+            Event::InsuranceLoose => OwnedEvent::InsuranceLoose,
+            Event::Bankroll(balance) => OwnedEvent::Bankroll(balance),
+            Event::Reshuffle => OwnedEvent::Reshuffle,
+            Event::PlayerHand(hand) => OwnedEvent::PlayerHand(cards(hand)),
+            Event::DealerHand(hand) => OwnedEvent::DealerHand(cards(hand)),
+            Event::DealerUpcard(upcard) => OwnedEvent::DealerUpcard(card(upcard)),
+            Event::DealerReveal(hand) => OwnedEvent::DealerReveal(cards(hand)),
+        }
+    }
+}
+
+impl<'a, H> From<&Event<'a, H>> for OwnedEvent<&'a H::Card>
+where
+    H: CardIter,
+{
+    /// Borrow the cards of an event, each hand as a list of its cards.
+    fn from(event: &Event<'a, H>) -> Self {
+        OwnedEvent::from_event(event, |card| card)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<H> Serialize for Event<'_, H>
+where
+    H: CardIter,
+    H::Card: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        OwnedEvent::from(self).serialize(serializer)
+    }
 }
 
 /// A trait for user interfaces. Implement this to create a new GUI, CUI, etc.
@@ -31,8 +154,21 @@ pub trait Interface<H>
 where
     H: CardIter,
 {
-    /// Get the player’s turn action.
-    fn get_action(&mut self) -> Action;
+    /// Get the player’s wager for the next round, between the table minimum and maximum.
+    fn get_wager(&mut self, min: u32, max: u32) -> u32;
+
+    /// Get the player’s turn action, out of the actions currently offered.
+    fn get_action(&mut self, actions: &[Action]) -> Action;
+
+    /// Get the player’s insurance wager when the dealer shows an ace, up to half their wager.
+    /// Insurance is declined with a wager of zero.
+    fn get_insurance(&mut self, max: u32) -> u32;
+
+    /// Ask if the player takes even money on their natural, when the dealer shows an ace.
+    fn get_even_money(&mut self) -> bool;
+
+    /// Ask if the player would like to play another round.
+    fn play_again(&mut self) -> bool;
 
     /// Display an event to the player.
     fn send(&mut self, event: Event<H>);
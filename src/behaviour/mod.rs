@@ -12,3 +12,9 @@ pub mod score;
 
 /// Trait for playing card like entities
 pub mod card_like;
+
+/// Trait for splitting a hand of cards.
+pub mod split;
+
+/// Trait for reshuffling a collection of cards.
+pub mod reshuffle;
@@ -0,0 +1,11 @@
+use mockall::automock;
+
+/// The ability to reshuffle a collection of cards once it has been dealt down to its cut card.
+#[automock]
+pub trait Reshuffle {
+    /// Check if the cut card has been reached, so a reshuffle is due before the next round.
+    fn needs_reshuffle(&self) -> bool;
+
+    /// Gather up every card, and shuffle them again.
+    fn reshuffle(&mut self);
+}
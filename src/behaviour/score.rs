@@ -1,14 +1,32 @@
 use mockall::automock;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
 
 /// The score of a blackjack hand.
+/// Value::Blackjack is a natural, a two card 21, and beats any other hand totalling 21.
 /// Value::Points() returns the actual point value, and if it counts as soft.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Value {
     Bust,
     Blackjack,
     Points(u8, bool),
 }
 
+impl Display for Value {
+    /// Describe the total, such as "bust", "blackjack", "soft 17" or "17".
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Bust => write!(f, "bust"),
+            Value::Blackjack => write!(f, "blackjack"),
+            Value::Points(points, true) => write!(f, "soft {}", points),
+            Value::Points(points, false) => write!(f, "{}", points),
+        }
+    }
+}
+
 /// The ability to evaluate the blackjack value of cards.
 #[automock]
 pub trait Score {
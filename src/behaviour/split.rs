@@ -0,0 +1,9 @@
+use mockall::automock;
+
+/// The ability to split a hand of cards into two.
+#[automock]
+pub trait Split {
+    /// Move the last card of this hand into a new hand.
+    /// Neither hand can score as a natural afterwards.
+    fn split(&mut self) -> Self;
+}
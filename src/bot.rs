@@ -0,0 +1,82 @@
+use crate::backend::card::Rank;
+use crate::backend::strategy::Strategy;
+use crate::behaviour::card_iter::CardIter;
+use crate::behaviour::card_like::CardLike;
+use crate::behaviour::interface::{Action, Event, Interface};
+
+/// Automated player for a blackjack game, which looks up each decision in a strategy chart.
+/// It wagers the same amount every round, never takes insurance or even money, and stops after
+/// a number of rounds.
+#[derive(Debug)]
+pub struct Bot {
+    strategy: Strategy,
+    wager: u32,
+    rounds: u32,
+    played: u32,
+    cards: Vec<Rank>,
+    upcard: Option<Rank>,
+}
+
+impl Bot {
+    /// Create a new Bot, which plays a number of rounds by a strategy, wagering the minimum bet.
+    pub fn new(strategy: Strategy, rounds: u32) -> Bot {
+        Bot {
+            strategy,
+            wager: 0,
+            rounds,
+            played: 0,
+            cards: Vec::new(),
+            upcard: None,
+        }
+    }
+
+    /// Wager an amount every round, kept within the table's limits.
+    pub fn with_wager(self, wager: u32) -> Bot {
+        Bot { wager, ..self }
+    }
+
+    /// Get the number of rounds played so far.
+    pub fn played(&self) -> u32 {
+        self.played
+    }
+}
+
+impl<H> Interface<H> for Bot
+where
+    H: CardIter,
+{
+    fn get_wager(&mut self, min: u32, max: u32) -> u32 {
+        self.wager.max(min).min(max)
+    }
+
+    fn get_action(&mut self, actions: &[Action]) -> Action {
+        match self.upcard {
+            Some(upcard) => self.strategy.advise(&self.cards, upcard, actions),
+            None => Action::PlayerStay,
+        }
+    }
+
+    fn get_insurance(&mut self, _max: u32) -> u32 {
+        0
+    }
+
+    fn get_even_money(&mut self) -> bool {
+        false
+    }
+
+    fn play_again(&mut self) -> bool {
+        self.played += 1;
+        self.played < self.rounds
+    }
+
+    fn send(&mut self, event: Event<H>) {
+        match event {
+            Event::PlayerHand(hand) => {
+                self.cards.clear();
+                self.cards.extend(hand.iter().map(|c| c.get_rank()));
+            }
+            Event::DealerUpcard(card) => self.upcard = Some(card.get_rank()),
+            _ => {}
+        }
+    }
+}
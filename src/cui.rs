@@ -1,49 +1,405 @@
+use crate::backend::card::{Rank, Suit};
+use crate::backend::rules::Rules;
+use crate::backend::strategy::Strategy;
+use crate::backend::style::{Style, Styled};
 use crate::behaviour::card_iter::CardIter;
+use crate::behaviour::card_like::CardLike;
 use crate::behaviour::interface::{Action, Event, Interface};
-use std::fmt::Display;
-use std::io::stdin;
+use crate::behaviour::score::Score;
+use std::io::{stdin, stdout, BufRead, IsTerminal, StdinLock, Stdout, Write};
+
+/// ASCII-art box drawn for the dealer's face-down hole card.
+const HOLE_CARD_ART: [&str; 5] = ["+-----+", "|/////|", "|/////|", "|/////|", "+-----+"];
+
+/// Width of a card drawn as ASCII art.
+const CARD_ART_WIDTH: usize = 7;
 
 /// Simple CUI interface for a blackjack game.
+/// In rich mode, the dealer's and player's hands are drawn side by side as coloured card art.
+/// With hints, the player can ask for the basic strategy play with "?".
+/// Answers are read from stdin and everything is written to stdout, unless other streams are
+/// given. Once the input closes, the round in play is finished by standing, and no more rounds
+/// are played.
 #[derive(Debug)]
-pub struct Cui {}
+pub struct Cui<R = StdinLock<'static>, W = Stdout>
+where
+    R: BufRead,
+    W: Write,
+{
+    input: R,
+    output: W,
+    style: Style,
+    rich: bool,
+    dealer: Column,
+    player: Column,
+    strategy: Option<Strategy>,
+    cards: Vec<Rank>,
+    upcard: Option<Rank>,
+}
+
+/// A hand drawn as coloured card art in rich mode, under a heading with its total.
+#[derive(Debug, Default)]
+struct Column {
+    heading: String,
+    lines: Vec<String>,
+    width: usize,
+}
 
 impl Cui {
-    /// Create a new Cui.
+    /// Create a new Cui, which writes cards out in full.
     pub fn new() -> Cui {
-        Cui {}
+        Cui::with_style(Style::Long)
+    }
+
+    /// Create a new Cui, which writes cards out in a style.
+    pub fn with_style(style: Style) -> Cui {
+        Cui {
+            style,
+            ..Cui::with_io(stdin().lock(), stdout())
+        }
+    }
+
+    /// Create a new Cui in rich mode, which falls back to plain text when stdout isn't a terminal.
+    pub fn rich() -> Cui {
+        Cui {
+            rich: stdout().is_terminal(),
+            ..Cui::new()
+        }
+    }
+}
+
+impl<R, W> Cui<R, W>
+where
+    R: BufRead,
+    W: Write,
+{
+    /// Create a new Cui which writes cards out in full, reading from and writing to any stream.
+    pub fn with_io(input: R, output: W) -> Cui<R, W> {
+        Cui {
+            input,
+            output,
+            style: Style::Long,
+            rich: false,
+            dealer: Column::default(),
+            player: Column::default(),
+            strategy: None,
+            cards: Vec::new(),
+            upcard: None,
+        }
+    }
+
+    /// Draw hands in rich mode, whether or not the output is a terminal.
+    pub fn with_rich_mode(self) -> Cui<R, W> {
+        Cui { rich: true, ..self }
+    }
+
+    /// Offer hints on how to play each hand, by basic strategy for a set of house rules.
+    pub fn with_hints(self, rules: Rules) -> Cui<R, W> {
+        Cui {
+            strategy: Some(Strategy::new(rules)),
+            ..self
+        }
+    }
+
+    /// Get the stream written to, such as to inspect what was written.
+    pub fn output(&self) -> &W {
+        &self.output
+    }
+
+    /// Write a line out.
+    fn println(&mut self, line: &str) {
+        writeln!(self.output, "{}", line).expect("failed to write output");
+    }
+
+    /// Read a line in, trimmed and in lower case.
+    /// Returns None once the input is closed.
+    fn read_line(&mut self) -> Option<String> {
+        let mut input = String::new();
+        if self.input.read_line(&mut input).ok()? == 0 {
+            return None;
+        }
+        Some(input.trim().to_lowercase())
+    }
+
+    /// Ask the player a yes or no question.
+    /// Returns None once the input is closed.
+    fn ask(&mut self, question: &str) -> Option<bool> {
+        loop {
+            self.println(&format!("{} (y)es or (n)o?", question));
+            match self.read_line()?.as_str() {
+                "y" => return Some(true),
+                "n" => return Some(false),
+                _ => {}
+            }
+        }
+    }
+
+    /// Ask the player for an amount of chips, within a range.
+    /// Returns None once the input is closed.
+    fn ask_amount(&mut self, question: &str, min: u32, max: u32) -> Option<u32> {
+        loop {
+            self.println(question);
+            match self.read_line()?.parse() {
+                Ok(amount) if (min..=max).contains(&amount) => return Some(amount),
+                _ => {}
+            }
+        }
+    }
+
+    /// Print the dealer's and player's hands side by side, with their totals.
+    fn show_table(&mut self) {
+        let width = self.dealer.width.max(self.dealer.heading.len()) + 4;
+        let padding = |column: &Column, line: &str| {
+            width.saturating_sub(if line.is_empty() { 0 } else { column.width })
+        };
+        let mut table = vec![format!(
+            "{:<width$}{}",
+            self.dealer.heading,
+            self.player.heading,
+            width = width
+        )];
+        let empty = String::new();
+        let lines = self.dealer.lines.len().max(self.player.lines.len());
+        for i in 0..lines {
+            let dealer = self.dealer.lines.get(i).unwrap_or(&empty);
+            let player = self.player.lines.get(i).unwrap_or(&empty);
+            table.push(format!(
+                "{}{}{}",
+                dealer,
+                " ".repeat(padding(&self.dealer, dealer)),
+                player
+            ));
+        }
+        self.println(&table.join("\n"));
+    }
+
+    /// Print a hand of cards, after a description of whose they are.
+    fn show<H: CardIter>(&mut self, description: &str, hand: &H) {
+        self.show_cards(
+            description,
+            Styled(hand.iter().as_slice(), self.style).to_string(),
+        );
+    }
+
+    /// Print written out cards after a description, on their own lines if drawn as art.
+    fn show_cards(&mut self, description: &str, cards: String) {
+        match self.style {
+            Style::Art => self.println(&format!("{}\n{}", description, cards)),
+            _ => self.println(&format!("{} {}", description, cards)),
+        }
+    }
+}
+
+impl Default for Cui {
+    fn default() -> Self {
+        Cui::new()
     }
 }
 
-impl<H> Interface<H> for Cui
+impl<R, W, H> Interface<H> for Cui<R, W>
 where
-    H: CardIter + Display,
+    R: BufRead,
+    W: Write,
+    H: CardIter + Score,
 {
-    fn get_action(self: &mut Cui) -> Action {
+    fn get_wager(&mut self, min: u32, max: u32) -> u32 {
+        self.ask_amount(
+            &format!("Place your wager, from {} to {}.", min, max),
+            min,
+            max,
+        )
+        .unwrap_or(min)
+    }
+
+    fn get_action(&mut self, actions: &[Action]) -> Action {
+        let options: Vec<&str> = actions.iter().map(|a| describe(*a).1).collect();
+        let prompt = match options.split_last() {
+            Some((last, [])) => last.to_string(),
+            Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+            None => String::new(),
+        };
+        let hint = match (&self.strategy, self.upcard) {
+            (Some(strategy), Some(upcard)) => Some(strategy.advise(&self.cards, upcard, actions)),
+            _ => None,
+        };
         loop {
-            println!("Would you like to (h)it or (s)tay?");
-            let mut input = String::new();
-            stdin().read_line(&mut input).unwrap();
-            if input.trim().to_lowercase() == "h" {
-                return Action::PlayerHit;
-            } else if input.trim().to_lowercase() == "s" {
+            match hint {
+                Some(_) => self.println(&format!("Would you like to {}? (? for a hint)", prompt)),
+                None => self.println(&format!("Would you like to {}?", prompt)),
+            }
+            let Some(input) = self.read_line() else {
                 return Action::PlayerStay;
+            };
+            if let Some(action) = actions.iter().find(|a| describe(**a).0 == input) {
+                return *action;
+            }
+            if let (Some(hint), "?") = (hint, input.as_str()) {
+                self.println(&format!("Basic strategy says to {}.", describe(hint).2));
             }
         }
     }
 
-    fn send(self: &mut Cui, event: Event<H>) {
+    fn get_insurance(&mut self, max: u32) -> u32 {
+        self.ask_amount(
+            &format!(
+                "The dealer shows an ace. How much insurance would you like, up to {}?",
+                max
+            ),
+            0,
+            max,
+        )
+        .unwrap_or(0)
+    }
+
+    fn get_even_money(&mut self) -> bool {
+        self.ask(
+            "You have blackjack, and the dealer shows an ace. Would you like to take even money?",
+        )
+        .unwrap_or(false)
+    }
+
+    fn play_again(&mut self) -> bool {
+        self.ask("Would you like to play again?").unwrap_or(false)
+    }
+
+    fn send(&mut self, event: Event<H>) {
+        match event {
+            Event::PlayerHand(hand) => self.cards = hand.iter().map(|c| c.get_rank()).collect(),
+            Event::DealerUpcard(card) => self.upcard = Some(card.get_rank()),
+            _ => {}
+        }
+        if self.rich {
+            match event {
+                Event::PlayerHand(hand) => {
+                    self.player = draw(hand.iter(), false, format!("You: {}", hand.score()));
+                    return self.show_table();
+                }
+                Event::DealerHand(hand) | Event::DealerReveal(hand) => {
+                    let heading = format!("Dealer: {}", hand.score());
+                    self.dealer = draw(hand.iter(), false, heading);
+                    return self.show_table();
+                }
+                Event::DealerUpcard(card) => {
+                    let heading = format!("Dealer: showing {}", upcard(card.get_rank()));
+                    self.dealer = draw([card].into_iter(), true, heading);
+                    return self.show_table();
+                }
+                // The round is over, so the next one starts from an empty table.
+                Event::Bankroll(_) => {
+                    self.dealer = Column::default();
+                    self.player = Column::default();
+                }
+                _ => {}
+            }
+        }
         match event {
-            Event::PlayerWin => println!("You win!"),
-            Event::PlayerLoose => println!("The dealer wins!"),
-            Event::Tie => println!("The game has ended in a draw."),
-            Event::PlayerBust => println!("You bust!"),
-            Event::PlayerBlackjack => println!("You blackjack!"),
-            Event::DealerHit => println!("The dealer hits."),
-            Event::DealerStay => println!("The dealer stays."),
-            Event::DealerBust => println!("The dealer busts!"),
-            Event::DealerBlackjack => println!("The dealer blackjacks!"),
-            Event::PlayerHand(hand) => println!("Your cards: {}", hand),
-            Event::DealerHand(hand) => println!("Dealers hand: {}", hand),
+            Event::PlayerWin => self.println("You win!"),
+            Event::PlayerLoose => self.println("The dealer wins!"),
+            Event::Tie => self.println("The game has ended in a draw."),
+            Event::PlayerSurrender => self.println("You surrender half your wager."),
+            Event::PlayerEvenMoney => self.println("You are paid even money."),
+            Event::InsuranceWin => self.println("Your insurance pays out."),
+            Event::InsuranceLoose => self.println("You lose your insurance."),
+            Event::Bankroll(balance) => self.println(&format!("Your balance is {}.", balance)),
+            Event::Reshuffle => self.println("The dealer reshuffles the deck."),
+            Event::PlayerBust => self.println("You bust!"),
+            Event::PlayerBlackjack => self.println("You blackjack!"),
+            Event::PlayerDouble => self.println("You double down."),
+            Event::PlayerSplit => self.println("You split your hand."),
+            Event::CurrentHand(index) => self.println(&format!("Hand {}:", index + 1)),
+            Event::DealerHit => self.println("The dealer hits."),
+            Event::DealerStay => self.println("The dealer stays."),
+            Event::DealerBust => self.println("The dealer busts!"),
+            Event::DealerBlackjack => self.println("The dealer blackjacks!"),
+            Event::PlayerHand(hand) => self.show("Your cards:", hand),
+            Event::DealerHand(hand) => self.show("Dealers hand:", hand),
+            Event::DealerUpcard(card) => {
+                let card = Styled(card, self.style).to_string();
+                let cards = match self.style {
+                    Style::Art => card
+                        .lines()
+                        .zip(HOLE_CARD_ART)
+                        .map(|(card, hole)| format!("{} {}", card, hole))
+                        .collect::<Vec<String>>()
+                        .join("\n"),
+                    Style::Long => format!("{}, ??", card),
+                    _ => format!("{} ??", card),
+                };
+                self.show_cards("Dealers hand:", cards)
+            }
+            Event::DealerReveal(hand) => self.show("The dealer reveals:", hand),
         }
     }
 }
+
+/// Draw cards as coloured ASCII art side by side, followed by a face-down hole card if hidden.
+fn draw<'a, C: CardLike + 'a>(
+    cards: impl Iterator<Item = &'a C>,
+    hole_card: bool,
+    heading: String,
+) -> Column {
+    let mut boxes: Vec<Vec<String>> = cards
+        .map(|c| {
+            let colour = colour(c.get_suit());
+            Styled(c, Style::Art)
+                .to_string()
+                .lines()
+                .map(|line| format!("\x1b[{}m{}\x1b[0m", colour, line))
+                .collect()
+        })
+        .collect();
+    if hole_card {
+        boxes.push(
+            HOLE_CARD_ART
+                .iter()
+                .map(|line| format!("\x1b[37;44m{}\x1b[0m", line))
+                .collect(),
+        );
+    }
+    let lines = (0..HOLE_CARD_ART.len())
+        .map(|i| {
+            let line: Vec<&str> = boxes.iter().map(|b| b[i].as_str()).collect();
+            line.join(" ")
+        })
+        .collect();
+    Column {
+        heading,
+        lines,
+        width: (boxes.len() * (CARD_ART_WIDTH + 1)).saturating_sub(1),
+    }
+}
+
+/// The ANSI colour a card of a suit is drawn in, red or black on a white card.
+fn colour(suit: Suit) -> &'static str {
+    match suit {
+        Suit::Heart | Suit::Diamond => "31;47",
+        Suit::Spade | Suit::Club => "30;47",
+    }
+}
+
+/// Get the points of the dealer's upcard on its own, counting an ace as 11.
+fn upcard(rank: Rank) -> u8 {
+    match rank {
+        Rank::Ace => 11,
+        Rank::Two => 2,
+        Rank::Three => 3,
+        Rank::Four => 4,
+        Rank::Five => 5,
+        Rank::Six => 6,
+        Rank::Seven => 7,
+        Rank::Eight => 8,
+        Rank::Nine => 9,
+        _ => 10,
+    }
+}
+
+/// The key used to pick an action, how it is described in the prompt, and its name.
+fn describe(action: Action) -> (&'static str, &'static str, &'static str) {
+    match action {
+        Action::PlayerHit => ("h", "(h)it", "hit"),
+        Action::PlayerStay => ("s", "(s)tay", "stay"),
+        Action::PlayerDouble => ("d", "(d)ouble", "double"),
+        Action::PlayerSplit => ("p", "s(p)lit", "split"),
+        Action::PlayerSurrender => ("r", "su(r)render", "surrender"),
+    }
+}
@@ -0,0 +1,163 @@
+use crate::behaviour::card_iter::CardIter;
+use crate::behaviour::interface::{Action, Event, Interface, OwnedEvent};
+use crate::behaviour::score::{Score, Value};
+use serde::{Deserialize, Serialize};
+use std::io::{stdin, stdout, BufRead, StdinLock, Stdout, Write};
+
+/// Machine-readable interface for a blackjack game, speaking JSON objects one per line.
+/// Every event is written out as it serializes, such as `{"event":"bankroll","value":990}`,
+/// with hands as their cards and score. When a decision is needed a `{"request": ...}` is
+/// written, and answered by a line read back in, such as `{"wager": 20}`, `{"action": "hit"}`,
+/// `{"insurance": 0}`, `{"even_money": true}` or `{"play_again": false}`. A line which doesn't
+/// answer the request gets an `{"error": ...}`, and is asked for again. Once the input closes,
+/// the round in play is finished by standing, and no more rounds are played.
+#[derive(Debug)]
+pub struct JsonLines<R, W>
+where
+    R: BufRead,
+    W: Write,
+{
+    input: R,
+    output: W,
+}
+
+/// A decision asked of the player.
+#[derive(Serialize)]
+#[serde(tag = "request", rename_all = "snake_case")]
+enum Request<'a> {
+    Wager { min: u32, max: u32 },
+    Action { actions: &'a [Action] },
+    Insurance { max: u32 },
+    EvenMoney,
+    PlayAgain,
+}
+
+/// A line read back in, holding the answer to a request among any other fields.
+#[derive(Deserialize)]
+struct Answer {
+    wager: Option<u32>,
+    action: Option<Action>,
+    insurance: Option<u32>,
+    even_money: Option<bool>,
+    play_again: Option<bool>,
+}
+
+/// An event, along with the score of the hand it shows, if any.
+#[derive(Serialize)]
+struct EventLine<'a, C> {
+    #[serde(flatten)]
+    event: OwnedEvent<&'a C>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    score: Option<Value>,
+}
+
+/// An answer which was read, but not accepted.
+#[derive(Serialize)]
+struct Error {
+    error: String,
+}
+
+impl JsonLines<StdinLock<'static>, Stdout> {
+    /// Create a new JsonLines, reading from stdin and writing to stdout.
+    pub fn stdio() -> JsonLines<StdinLock<'static>, Stdout> {
+        JsonLines::new(stdin().lock(), stdout())
+    }
+}
+
+impl<R, W> JsonLines<R, W>
+where
+    R: BufRead,
+    W: Write,
+{
+    /// Create a new JsonLines, reading from and writing to any stream.
+    pub fn new(input: R, output: W) -> JsonLines<R, W> {
+        JsonLines { input, output }
+    }
+
+    /// Get the stream written to, such as to inspect what was written.
+    pub fn output(&self) -> &W {
+        &self.output
+    }
+
+    /// Write a JSON object out as a line.
+    fn write(&mut self, message: &impl Serialize) {
+        serde_json::to_writer(&mut self.output, message).expect("failed to write output");
+        writeln!(self.output).expect("failed to write output");
+        self.output.flush().expect("failed to write output");
+    }
+
+    /// Write a request, then read lines until one holds an answer that `answer` accepts.
+    /// Returns None once the input is closed.
+    fn request<T>(
+        &mut self,
+        request: Request,
+        key: &str,
+        answer: impl Fn(Answer) -> Option<T>,
+    ) -> Option<T> {
+        self.write(&request);
+        loop {
+            let mut line = String::new();
+            if self.input.read_line(&mut line).ok()? == 0 {
+                return None;
+            }
+            match serde_json::from_str(&line).ok().and_then(&answer) {
+                Some(value) => return Some(value),
+                None => self.write(&Error {
+                    error: format!("expected a valid \"{}\"", key),
+                }),
+            }
+        }
+    }
+}
+
+impl<R, W, H> Interface<H> for JsonLines<R, W>
+where
+    R: BufRead,
+    W: Write,
+    H: CardIter + Score,
+    H::Card: Serialize,
+{
+    fn get_wager(&mut self, min: u32, max: u32) -> u32 {
+        self.request(Request::Wager { min, max }, "wager", |a| {
+            a.wager.filter(|w| (min..=max).contains(w))
+        })
+        .unwrap_or(min)
+    }
+
+    fn get_action(&mut self, actions: &[Action]) -> Action {
+        self.request(Request::Action { actions }, "action", |a| {
+            a.action.filter(|a| actions.contains(a))
+        })
+        .unwrap_or(Action::PlayerStay)
+    }
+
+    fn get_insurance(&mut self, max: u32) -> u32 {
+        self.request(Request::Insurance { max }, "insurance", |a| {
+            a.insurance.filter(|i| *i <= max)
+        })
+        .unwrap_or(0)
+    }
+
+    fn get_even_money(&mut self) -> bool {
+        self.request(Request::EvenMoney, "even_money", |a| a.even_money)
+            .unwrap_or(false)
+    }
+
+    fn play_again(&mut self) -> bool {
+        self.request(Request::PlayAgain, "play_again", |a| a.play_again)
+            .unwrap_or(false)
+    }
+
+    fn send(&mut self, event: Event<H>) {
+        let score = match event {
+            Event::PlayerHand(hand) | Event::DealerHand(hand) | Event::DealerReveal(hand) => {
+                Some(hand.score())
+            }
+            _ => None,
+        };
+        self.write(&EventLine {
+            event: OwnedEvent::from(&event),
+            score,
+        });
+    }
+}
@@ -6,3 +6,16 @@ pub mod backend;
 
 /// Implementation of blackjack cui frontend.
 pub mod cui;
+
+/// Implementation of blackjack full-screen tui frontend.
+pub mod tui;
+
+/// Implementation of blackjack json-lines frontend, for bots and tooling.
+#[cfg(feature = "serde")]
+pub mod json;
+
+/// Implementation of an automated player, which plays by a strategy chart.
+pub mod bot;
+
+/// Implementation of a headless Monte Carlo simulator, for evaluating strategies.
+pub mod simulation;
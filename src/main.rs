@@ -1,14 +1,121 @@
+use blackjack::backend::bankroll::Bankroll;
 use blackjack::backend::blackjack::Blackjack;
-use blackjack::backend::deck::Deck;
+use blackjack::backend::card::Card;
 use blackjack::backend::hand::Hand;
+use blackjack::backend::history::History;
+use blackjack::backend::rules::Rules;
+use blackjack::backend::shoe::Shoe;
+use blackjack::backend::strategy::Strategy;
+use blackjack::behaviour::interface::Interface;
 use blackjack::cui::Cui;
+#[cfg(feature = "serde")]
+use blackjack::json::JsonLines;
+use blackjack::simulation::Simulation;
+use blackjack::tui::{TerminalKeys, Tui};
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::thread;
 
 fn main() {
-    Blackjack::new(
-        &mut Cui::new(),
-        &mut Deck::new(),
-        &mut Hand::new(),
-        &mut Hand::new(),
-    )
-    .start();
+    let args: Vec<String> = env::args().collect();
+    let option = |name: &str| {
+        let index = args.iter().position(|arg| arg == name)?;
+        args.get(index + 1).cloned()
+    };
+    let flag = |name: &str| args.iter().any(|arg| arg == name);
+    if let Some(path) = option("--replay") {
+        replay(&path);
+    } else if flag("--chart") {
+        print!("{}", Strategy::new(Rules::default()));
+    } else if let Some(rounds) = option("--simulate") {
+        simulate(
+            &rounds,
+            option("--strategy"),
+            option("--seed"),
+            option("--threads"),
+        );
+    } else if flag("--tui") {
+        run(
+            &mut Tui::new(ratatui::init(), TerminalKeys),
+            option("--record"),
+        );
+        ratatui::restore();
+    } else if flag("--json") {
+        #[cfg(feature = "serde")]
+        run(&mut JsonLines::stdio(), option("--record"));
+        #[cfg(not(feature = "serde"))]
+        eprintln!("--json needs the serde feature");
+    } else {
+        run(
+            &mut Cui::rich().with_hints(Rules::default()),
+            option("--record"),
+        );
+    }
+}
+
+/// Play blackjack through an interface until the player stops, recording the history of each
+/// round to a file if given.
+fn run<U: Interface<Hand<Card>>>(ui: &mut U, record: Option<String>) {
+    let rules = Rules::default();
+    let mut shoe = Shoe::new(rules.decks, 0.75);
+    let mut bankroll = Bankroll::new(1000);
+    let Some(path) = record else {
+        Blackjack::<_, _, _, Hand<Card>>::new(ui, &mut shoe, &mut bankroll, rules).play();
+        return;
+    };
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .expect("failed to open the hand history file");
+    History::record_session(ui, &mut shoe, &mut bankroll, rules, |history| {
+        writeln!(file, "{}", history).expect("failed to write the hand history file")
+    });
+}
+
+/// Replay each round of a hand history file, checking they play out as recorded.
+fn replay(path: &str) {
+    let histories = fs::read_to_string(path).expect("failed to read the hand history file");
+    for (round, history) in histories.split("\n\n").enumerate() {
+        if history.trim().is_empty() {
+            continue;
+        }
+        println!("Round {}:", round + 1);
+        match history.parse::<History>() {
+            Ok(history) => match history.replay(&mut Cui::new()) {
+                Ok(()) => println!("The round played out as recorded."),
+                Err(error) => println!("The round did not play out as recorded: {:?}", error),
+            },
+            Err(error) => println!("The round could not be read: {:?}", error),
+        }
+    }
+}
+
+/// Simulate a number of rounds by a strategy chart from a file, or by basic strategy, and print
+/// the results. Rounds are split across a number of threads, by default one per core.
+fn simulate(rounds: &str, strategy: Option<String>, seed: Option<String>, threads: Option<String>) {
+    let rules = Rules::default();
+    let rounds = rounds
+        .parse()
+        .expect("the number of rounds should be a whole number");
+    let strategy = match strategy {
+        Some(path) => fs::read_to_string(path)
+            .expect("failed to read the strategy chart")
+            .parse()
+            .expect("failed to parse the strategy chart"),
+        None => Strategy::new(rules),
+    };
+    let mut simulation = Simulation::new(strategy, rules);
+    if let Some(seed) = seed {
+        simulation = simulation.with_seed(seed.parse().expect("the seed should be a whole number"));
+    }
+    let threads = match threads {
+        Some(threads) => threads
+            .parse()
+            .expect("the number of threads should be a whole number"),
+        None => thread::available_parallelism().map_or(1, |threads| threads.get()),
+    };
+    println!("Seed: {} ({} threads)", simulation.seed(), threads);
+    print!("{}", simulation.run_parallel(rounds, threads));
 }
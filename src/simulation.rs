@@ -0,0 +1,301 @@
+use crate::backend::bankroll::Bankroll;
+use crate::backend::blackjack::Blackjack;
+use crate::backend::card::Card;
+use crate::backend::hand::Hand;
+use crate::backend::rules::Rules;
+use crate::backend::shoe::Shoe;
+use crate::backend::strategy::Strategy;
+use crate::behaviour::interface::{Action, Event, Interface};
+use crate::bot::Bot;
+use rand::rngs::StdRng;
+use rand::{thread_rng, Rng, SeedableRng};
+use std::fmt;
+use std::thread;
+
+/// Balance the simulated player starts with, deep enough to never run out over any simulation.
+const BANKROLL: u32 = u32::MAX / 2;
+
+/// The z-score of a 95% confidence interval.
+const Z_95: f64 = 1.96;
+
+/// Headless Monte Carlo simulation of many rounds of blackjack, played by a strategy chart.
+/// Rounds are played through the same engine as an interactive game, from a seeded shoe, so a
+/// simulation with the same seed plays out the same way.
+#[derive(Debug, Clone)]
+pub struct Simulation {
+    strategy: Strategy,
+    rules: Rules,
+    penetration: f64,
+    seed: u64,
+    wager: u32,
+}
+
+/// Results of a simulation, counted over every round played.
+/// Wins, losses, pushes and surrenders are counted per hand, so a split round counts each of its
+/// hands. Results are kept in whole chips, so they add up exactly.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct Statistics {
+    wager: u32,
+    rounds: u64,
+    wins: u64,
+    losses: u64,
+    pushes: u64,
+    surrenders: u64,
+    blackjacks: u64,
+    net: i64,
+    net_squared: u64,
+}
+
+/// Player for a simulation, which plays by a bot and tallies up the results of each round.
+struct Tally {
+    bot: Bot,
+    rounds: u64,
+    balance: u32,
+    statistics: Statistics,
+}
+
+impl Simulation {
+    /// Create a new simulation of a strategy played by a set of house rules, with a shoe of the
+    /// rules' number of decks cut three quarters of the way through, shuffled from a random seed.
+    /// The minimum bet is wagered every round.
+    pub fn new(strategy: Strategy, rules: Rules) -> Simulation {
+        Simulation {
+            strategy,
+            rules,
+            penetration: 0.75,
+            seed: thread_rng().gen(),
+            wager: rules.min_bet,
+        }
+    }
+
+    /// Cut the shoe at a penetration between 0 and 1.
+    pub fn with_penetration(self, penetration: f64) -> Simulation {
+        Simulation {
+            penetration,
+            ..self
+        }
+    }
+
+    /// Shuffle the shoe from a seed, so the simulation can be run again with the same results.
+    pub fn with_seed(self, seed: u64) -> Simulation {
+        Simulation { seed, ..self }
+    }
+
+    /// Wager an amount every round, kept within the table's limits.
+    pub fn with_wager(self, wager: u32) -> Simulation {
+        Simulation {
+            wager: wager.max(self.rules.min_bet).min(self.rules.max_bet),
+            ..self
+        }
+    }
+
+    /// Get the seed the shoe is shuffled from.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Play a number of rounds split across threads, and count up their results.
+    /// Each thread plays its share of the rounds from its own shoe, shuffled from a seed drawn in
+    /// turn from the simulation's seed, and the results are added up in thread order. So the same
+    /// seed and number of threads always gives the same results.
+    pub fn run_parallel(&self, rounds: u64, threads: usize) -> Statistics {
+        let threads = threads.max(1);
+        let mut seeds = StdRng::seed_from_u64(self.seed);
+        let simulations: Vec<(Simulation, u64)> = (0..threads as u64)
+            .map(|thread| {
+                let share = rounds / threads as u64 + u64::from(thread < rounds % threads as u64);
+                (self.clone().with_seed(seeds.gen()), share)
+            })
+            .collect();
+        thread::scope(|scope| {
+            let handles: Vec<_> = simulations
+                .iter()
+                .map(|(simulation, rounds)| scope.spawn(|| simulation.run(*rounds)))
+                .collect();
+            handles.into_iter().fold(
+                Statistics {
+                    wager: self.wager,
+                    ..Statistics::default()
+                },
+                |mut statistics, handle| {
+                    statistics.merge(&handle.join().expect("a simulation thread panicked"));
+                    statistics
+                },
+            )
+        })
+    }
+
+    /// Play a number of rounds, and count up their results.
+    pub fn run(&self, rounds: u64) -> Statistics {
+        let mut tally = Tally {
+            bot: Bot::new(self.strategy.clone(), 0).with_wager(self.wager),
+            rounds,
+            balance: BANKROLL,
+            statistics: Statistics {
+                wager: self.wager,
+                ..Statistics::default()
+            },
+        };
+        if rounds == 0 {
+            return tally.statistics;
+        }
+        let mut shoe = Shoe::with_seed(self.rules.decks, self.penetration, self.seed);
+        let mut bankroll = Bankroll::new(BANKROLL);
+        Blackjack::<_, _, _, Hand<Card>>::new(&mut tally, &mut shoe, &mut bankroll, self.rules)
+            .play();
+        tally.statistics
+    }
+}
+
+impl Statistics {
+    /// Add in the results of more rounds, played with the same wager.
+    pub fn merge(&mut self, other: &Statistics) {
+        self.rounds += other.rounds;
+        self.wins += other.wins;
+        self.losses += other.losses;
+        self.pushes += other.pushes;
+        self.surrenders += other.surrenders;
+        self.blackjacks += other.blackjacks;
+        self.net += other.net;
+        self.net_squared += other.net_squared;
+    }
+
+    /// Get the amount wagered at the start of each round.
+    pub fn wager(&self) -> u32 {
+        self.wager
+    }
+
+    /// Get the number of rounds played.
+    pub fn rounds(&self) -> u64 {
+        self.rounds
+    }
+
+    /// Get the number of hands played, counting each hand of a split.
+    pub fn hands(&self) -> u64 {
+        self.wins + self.losses + self.pushes + self.surrenders
+    }
+
+    /// Get the net amount won over every round, in chips.
+    pub fn net(&self) -> i64 {
+        self.net
+    }
+
+    /// Get the expected value of a round, as a fraction of the starting wager.
+    pub fn ev(&self) -> f64 {
+        self.mean() / self.wager as f64
+    }
+
+    /// Get the expected value of a hand, counting each hand of a split, as a fraction of the
+    /// starting wager.
+    pub fn ev_per_hand(&self) -> f64 {
+        self.net as f64 / self.hands() as f64 / self.wager as f64
+    }
+
+    /// Get the standard deviation of a round's result, as a fraction of the starting wager.
+    pub fn std_dev(&self) -> f64 {
+        if self.rounds < 2 {
+            return 0.0;
+        }
+        let n = self.rounds as f64;
+        let variance = (self.net_squared as f64 - n * self.mean().powi(2)) / (n - 1.0);
+        variance.max(0.0).sqrt() / self.wager as f64
+    }
+
+    /// Get the 95% confidence interval of the expected value of a round.
+    pub fn confidence_interval(&self) -> (f64, f64) {
+        let margin = Z_95 * self.std_dev() / (self.rounds as f64).sqrt();
+        (self.ev() - margin, self.ev() + margin)
+    }
+
+    /// Get the fraction of hands won, including naturals and even money.
+    pub fn win_rate(&self) -> f64 {
+        self.wins as f64 / self.hands() as f64
+    }
+
+    /// Get the fraction of hands lost.
+    pub fn loss_rate(&self) -> f64 {
+        self.losses as f64 / self.hands() as f64
+    }
+
+    /// Get the fraction of hands pushed.
+    pub fn push_rate(&self) -> f64 {
+        self.pushes as f64 / self.hands() as f64
+    }
+
+    /// Get the fraction of hands surrendered.
+    pub fn surrender_rate(&self) -> f64 {
+        self.surrenders as f64 / self.hands() as f64
+    }
+
+    /// Get the fraction of rounds where the player is dealt a natural.
+    pub fn blackjack_rate(&self) -> f64 {
+        self.blackjacks as f64 / self.rounds as f64
+    }
+
+    /// The average result of a round, in chips.
+    fn mean(&self) -> f64 {
+        self.net as f64 / self.rounds as f64
+    }
+}
+
+impl fmt::Display for Statistics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (low, high) = self.confidence_interval();
+        writeln!(f, "Rounds: {} ({} hands)", self.rounds, self.hands())?;
+        writeln!(f, "EV per round: {:+.4}%", self.ev() * 100.0)?;
+        writeln!(f, "EV per hand: {:+.4}%", self.ev_per_hand() * 100.0)?;
+        writeln!(
+            f,
+            "95% confidence: {:+.4}% to {:+.4}%",
+            low * 100.0,
+            high * 100.0
+        )?;
+        writeln!(f, "Standard deviation: {:.4}", self.std_dev())?;
+        writeln!(f, "Wins: {:.4}%", self.win_rate() * 100.0)?;
+        writeln!(f, "Losses: {:.4}%", self.loss_rate() * 100.0)?;
+        writeln!(f, "Pushes: {:.4}%", self.push_rate() * 100.0)?;
+        writeln!(f, "Surrenders: {:.4}%", self.surrender_rate() * 100.0)?;
+        writeln!(f, "Blackjacks: {:.4}%", self.blackjack_rate() * 100.0)
+    }
+}
+
+impl Interface<Hand<Card>> for Tally {
+    fn get_wager(&mut self, min: u32, max: u32) -> u32 {
+        Interface::<Hand<Card>>::get_wager(&mut self.bot, min, max)
+    }
+
+    fn get_action(&mut self, actions: &[Action]) -> Action {
+        Interface::<Hand<Card>>::get_action(&mut self.bot, actions)
+    }
+
+    fn get_insurance(&mut self, max: u32) -> u32 {
+        Interface::<Hand<Card>>::get_insurance(&mut self.bot, max)
+    }
+
+    fn get_even_money(&mut self) -> bool {
+        Interface::<Hand<Card>>::get_even_money(&mut self.bot)
+    }
+
+    fn play_again(&mut self) -> bool {
+        self.statistics.rounds < self.rounds
+    }
+
+    fn send(&mut self, event: Event<Hand<Card>>) {
+        let statistics = &mut self.statistics;
+        match event {
+            Event::PlayerWin | Event::PlayerEvenMoney => statistics.wins += 1,
+            Event::PlayerLoose => statistics.losses += 1,
+            Event::Tie => statistics.pushes += 1,
+            Event::PlayerSurrender => statistics.surrenders += 1,
+            Event::PlayerBlackjack => statistics.blackjacks += 1,
+            Event::Bankroll(balance) => {
+                let net = balance as i64 - self.balance as i64;
+                self.balance = balance;
+                statistics.rounds += 1;
+                statistics.net += net;
+                statistics.net_squared += net.unsigned_abs().pow(2);
+            }
+            _ => self.bot.send(event),
+        }
+    }
+}
@@ -0,0 +1,354 @@
+use crate::backend::style::{Style, Styled};
+use crate::behaviour::card_iter::CardIter;
+use crate::behaviour::interface::{Action, Event, Interface};
+use crate::behaviour::score::Score;
+use ratatui::backend::Backend;
+use ratatui::crossterm::event::{self, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::{Frame, Terminal};
+
+/// Key code used for the enter key.
+pub const ENTER: char = '\n';
+
+/// Key code used for the backspace key.
+pub const BACKSPACE: char = '\x08';
+
+/// Key code used for the escape key, which quits, as does Ctrl-C.
+pub const ESCAPE: char = '\x1b';
+
+/// Full-screen terminal interface for a blackjack game.
+/// The dealer's hand is shown at the top, the player's at the bottom with an action bar beneath,
+/// alongside a bankroll panel and a log of events. Key presses are read from an iterator of
+/// characters, and the screen is drawn to any ratatui backend, so it can run headlessly.
+/// Pressing q or escape, or running out of key presses, quits: the round in play is finished by
+/// standing, without insurance or even money, and no more rounds are played.
+pub struct Tui<B, K>
+where
+    B: Backend,
+    K: Iterator<Item = char>,
+{
+    terminal: Terminal<B>,
+    keys: K,
+    screen: Screen,
+    quit: bool,
+}
+
+/// Everything shown on the screen.
+#[derive(Debug, Default)]
+struct Screen {
+    dealer: Panel,
+    player: Panel,
+    hand: Option<usize>,
+    balance: Option<u32>,
+    wager: Option<u32>,
+    prompt: String,
+    log: Vec<String>,
+}
+
+/// A hand of cards drawn in a panel, with its total.
+#[derive(Debug, Default)]
+struct Panel {
+    cards: String,
+    total: String,
+}
+
+impl<B, K> Tui<B, K>
+where
+    B: Backend,
+    K: Iterator<Item = char>,
+{
+    /// Create a new Tui, drawing to a terminal and reading key presses.
+    pub fn new(terminal: Terminal<B>, keys: K) -> Tui<B, K> {
+        Tui {
+            terminal,
+            keys,
+            screen: Screen::default(),
+            quit: false,
+        }
+    }
+
+    /// Get the terminal backend, such as to inspect a simulated screen.
+    pub fn backend(&self) -> &B {
+        self.terminal.backend()
+    }
+
+    /// Draw the whole screen.
+    fn draw(&mut self) {
+        let screen = &self.screen;
+        self.terminal
+            .draw(|frame| screen.render(frame))
+            .expect("failed to draw to the terminal");
+    }
+
+    /// Wait for the next key press, or none once the player has quit.
+    fn next_key(&mut self) -> Option<char> {
+        if !self.quit {
+            match self.keys.next() {
+                None | Some(ESCAPE) | Some('q') | Some('Q') => self.quit = true,
+                key => return key,
+            }
+        }
+        None
+    }
+
+    /// Show a prompt in the action bar, and wait for one of the keys it offers.
+    fn ask_key(&mut self, prompt: String, keys: &[char]) -> Option<char> {
+        self.screen.prompt = prompt;
+        self.draw();
+        loop {
+            let key = self.next_key()?.to_ascii_lowercase();
+            if keys.contains(&key) {
+                self.screen.prompt.clear();
+                return Some(key);
+            }
+        }
+    }
+
+    /// Show a prompt in the action bar, and read an amount of chips typed in, within a range.
+    fn ask_amount(&mut self, question: &str, min: u32, max: u32) -> Option<u32> {
+        let mut input = String::new();
+        loop {
+            self.screen.prompt = format!("{} {}_", question, input);
+            self.draw();
+            match self.next_key()? {
+                ENTER => match input.parse() {
+                    Ok(amount) if (min..=max).contains(&amount) => {
+                        self.screen.prompt.clear();
+                        return Some(amount);
+                    }
+                    _ => input.clear(),
+                },
+                BACKSPACE => {
+                    input.pop();
+                }
+                key if key.is_ascii_digit() => input.push(key),
+                _ => {}
+            }
+        }
+    }
+
+    /// Ask the player a yes or no question.
+    fn ask(&mut self, question: &str) -> bool {
+        self.ask_key(format!("{}  [y] yes  [n] no", question), &['y', 'n']) == Some('y')
+    }
+
+    /// Add a message to the event log.
+    fn log(&mut self, message: &str) {
+        self.screen.log.push(message.to_string());
+        self.draw();
+    }
+}
+
+impl<B, K, H> Interface<H> for Tui<B, K>
+where
+    B: Backend,
+    K: Iterator<Item = char>,
+    H: CardIter + Score,
+{
+    fn get_wager(&mut self, min: u32, max: u32) -> u32 {
+        self.screen.dealer = Panel::default();
+        self.screen.player = Panel::default();
+        self.screen.hand = None;
+        let wager = self
+            .ask_amount(&format!("Wager ({} to {}):", min, max), min, max)
+            .unwrap_or(min);
+        self.screen.wager = Some(wager);
+        wager
+    }
+
+    fn get_action(&mut self, actions: &[Action]) -> Action {
+        let prompt = actions
+            .iter()
+            .map(|a| format!("[{}] {}", key(*a), label(*a)))
+            .collect::<Vec<String>>()
+            .join("  ");
+        let keys: Vec<char> = actions.iter().map(|a| key(*a)).collect();
+        match self.ask_key(prompt, &keys) {
+            Some(pressed) => *actions.iter().find(|a| key(**a) == pressed).unwrap(),
+            None => Action::PlayerStay,
+        }
+    }
+
+    fn get_insurance(&mut self, max: u32) -> u32 {
+        self.ask_amount(&format!("Insurance (0 to {}):", max), 0, max)
+            .unwrap_or(0)
+    }
+
+    fn get_even_money(&mut self) -> bool {
+        self.ask("Take even money?")
+    }
+
+    fn play_again(&mut self) -> bool {
+        self.ask("Play again?")
+    }
+
+    fn send(&mut self, event: Event<H>) {
+        match event {
+            Event::PlayerWin => self.log("You win!"),
+            Event::PlayerLoose => self.log("The dealer wins!"),
+            Event::Tie => self.log("Push."),
+            Event::PlayerSurrender => self.log("You surrender half your wager."),
+            Event::PlayerEvenMoney => self.log("You are paid even money."),
+            Event::InsuranceWin => self.log("Your insurance pays out."),
+            Event::InsuranceLoose => self.log("You lose your insurance."),
+            Event::Bankroll(balance) => {
+                self.screen.balance = Some(balance);
+                self.screen.wager = None;
+                self.draw()
+            }
+            Event::Reshuffle => self.log("The dealer reshuffles the deck."),
+            Event::PlayerBust => self.log("You bust!"),
+            Event::PlayerBlackjack => self.log("You blackjack!"),
+            Event::PlayerDouble => self.log("You double down."),
+            Event::PlayerSplit => self.log("You split your hand."),
+            Event::CurrentHand(index) => {
+                self.screen.hand = Some(index);
+                self.log(&format!("Hand {}:", index + 1))
+            }
+            Event::DealerHit => self.log("The dealer hits."),
+            Event::DealerStay => self.log("The dealer stays."),
+            Event::DealerBust => self.log("The dealer busts!"),
+            Event::DealerBlackjack => self.log("The dealer blackjacks!"),
+            Event::PlayerHand(hand) => {
+                self.screen.player = panel(hand);
+                self.draw()
+            }
+            Event::DealerHand(hand) => {
+                self.screen.dealer = panel(hand);
+                self.draw()
+            }
+            Event::DealerUpcard(card) => {
+                self.screen.dealer = Panel {
+                    cards: Styled(card, Style::Art).to_string(),
+                    total: String::from("?"),
+                };
+                self.draw()
+            }
+            Event::DealerReveal(hand) => {
+                self.screen.dealer = panel(hand);
+                self.log("The dealer reveals their hole card.")
+            }
+        }
+    }
+}
+
+/// Key presses read from the real terminal, such as through crossterm.
+/// Ctrl-C is read as escape, and the keys run out if the terminal can't be read.
+#[derive(Debug, Default)]
+pub struct TerminalKeys;
+
+impl Iterator for TerminalKeys {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let event::Event::Key(key) = event::read().ok()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match key.code {
+                    KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        return Some(ESCAPE)
+                    }
+                    KeyCode::Char(c) => return Some(c),
+                    KeyCode::Enter => return Some(ENTER),
+                    KeyCode::Backspace => return Some(BACKSPACE),
+                    KeyCode::Esc => return Some(ESCAPE),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// Build the panel for a hand of cards.
+fn panel<H: CardIter + Score>(hand: &H) -> Panel {
+    Panel {
+        cards: Styled(hand.iter().as_slice(), Style::Art).to_string(),
+        total: hand.score().to_string(),
+    }
+}
+
+impl Screen {
+    /// Draw the table, action bar, bankroll panel and event log into a frame.
+    fn render(&self, frame: &mut Frame) {
+        let [table, side] =
+            Layout::horizontal([Constraint::Min(40), Constraint::Length(32)]).areas(frame.area());
+        let [dealer_area, player_area, action_area] = Layout::vertical([
+            Constraint::Min(7),
+            Constraint::Min(7),
+            Constraint::Length(3),
+        ])
+        .areas(table);
+        let [bankroll_area, log_area] =
+            Layout::vertical([Constraint::Length(4), Constraint::Min(3)]).areas(side);
+
+        let title = format!("Dealer ({})", self.dealer.total);
+        let title = if self.dealer.total.is_empty() {
+            "Dealer"
+        } else {
+            &title
+        };
+        frame.render_widget(
+            Paragraph::new(self.dealer.cards.as_str()).block(Block::bordered().title(title)),
+            dealer_area,
+        );
+        let title = match self.hand {
+            Some(index) => format!("You, hand {}", index + 1),
+            None => String::from("You"),
+        };
+        let title = if self.player.total.is_empty() {
+            title
+        } else {
+            format!("{} ({})", title, self.player.total)
+        };
+        frame.render_widget(
+            Paragraph::new(self.player.cards.as_str()).block(Block::bordered().title(title)),
+            player_area,
+        );
+        frame.render_widget(
+            Paragraph::new(self.prompt.as_str()).block(Block::bordered().title("Actions")),
+            action_area,
+        );
+
+        let amount = |amount: Option<u32>| amount.map_or(String::from("-"), |a| a.to_string());
+        frame.render_widget(
+            Paragraph::new(format!(
+                "Balance: {}\nWager: {}",
+                amount(self.balance),
+                amount(self.wager)
+            ))
+            .block(Block::bordered().title("Bankroll")),
+            bankroll_area,
+        );
+        let visible = log_area.height.saturating_sub(2) as usize;
+        let scroll = self.log.len().saturating_sub(visible);
+        frame.render_widget(
+            Paragraph::new(self.log[scroll..].join("\n")).block(Block::bordered().title("Log")),
+            log_area,
+        );
+    }
+}
+
+/// The key used to pick an action.
+fn key(action: Action) -> char {
+    match action {
+        Action::PlayerHit => 'h',
+        Action::PlayerStay => 's',
+        Action::PlayerDouble => 'd',
+        Action::PlayerSplit => 'p',
+        Action::PlayerSurrender => 'r',
+    }
+}
+
+/// How an action is described in the action bar.
+fn label(action: Action) -> &'static str {
+    match action {
+        Action::PlayerHit => "hit",
+        Action::PlayerStay => "stay",
+        Action::PlayerDouble => "double",
+        Action::PlayerSplit => "split",
+        Action::PlayerSurrender => "surrender",
+    }
+}
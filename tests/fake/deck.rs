@@ -0,0 +1,42 @@
+use crate::mock::card::mock_card;
+use blackjack::backend::card::{Rank, Suit};
+use blackjack::behaviour::card_like::MockCardLike;
+use blackjack::behaviour::draw::DrawFrom;
+use blackjack::behaviour::reshuffle::Reshuffle;
+
+pub struct FakeDeck {
+    cards: Vec<MockCardLike>,
+    cut_card: usize,
+    reshuffles: usize,
+}
+
+impl FakeDeck {
+    pub fn new(cards: Vec<(Rank, Suit)>, cut_card: usize) -> FakeDeck {
+        FakeDeck {
+            cards: cards.iter().rev().map(mock_card).collect(),
+            cut_card,
+            reshuffles: 0,
+        }
+    }
+
+    pub fn verify_reshuffle_times(&self, times: usize) {
+        assert_eq!(times, self.reshuffles)
+    }
+}
+
+impl DrawFrom<MockCardLike> for FakeDeck {
+    fn draw(&mut self) -> Option<MockCardLike> {
+        self.cards.pop()
+    }
+}
+
+impl Reshuffle for FakeDeck {
+    fn needs_reshuffle(&self) -> bool {
+        self.cards.len() <= self.cut_card
+    }
+
+    fn reshuffle(&mut self) {
+        self.reshuffles += 1;
+        self.cut_card = 0;
+    }
+}
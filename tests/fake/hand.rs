@@ -1,16 +1,23 @@
 use blackjack::backend::card::Rank;
 use blackjack::behaviour::card_iter::CardIter;
 use blackjack::behaviour::card_like::{CardLike, MockCardLike};
-use blackjack::behaviour::draw::{CannotDrawFromEmpty, DrawFrom, DrawTo, MockDrawFrom};
+use blackjack::behaviour::draw::{CannotDrawFromEmpty, DrawFrom, DrawTo};
 use blackjack::behaviour::score::{Score, Value};
+use blackjack::behaviour::split::Split;
 use std::cmp::Ordering;
 use std::slice::Iter;
 
-pub struct FakeHand(Vec<MockCardLike>);
+pub struct FakeHand(Vec<MockCardLike>, bool);
 
 impl FakeHand {
     pub fn new() -> FakeHand {
-        FakeHand(Vec::new())
+        FakeHand(Vec::new(), false)
+    }
+}
+
+impl Default for FakeHand {
+    fn default() -> Self {
+        FakeHand::new()
     }
 }
 
@@ -22,11 +29,11 @@ impl CardIter for FakeHand {
     }
 }
 
-impl DrawTo<MockCardLike, MockDrawFrom<MockCardLike>> for FakeHand {
-    fn draw_from(
-        &mut self,
-        cards: &mut MockDrawFrom<MockCardLike>,
-    ) -> Result<(), CannotDrawFromEmpty> {
+impl<D> DrawTo<MockCardLike, D> for FakeHand
+where
+    D: DrawFrom<MockCardLike>,
+{
+    fn draw_from(&mut self, cards: &mut D) -> Result<(), CannotDrawFromEmpty> {
         match cards.draw() {
             None => Err(CannotDrawFromEmpty),
             Some(card) => {
@@ -37,6 +44,13 @@ impl DrawTo<MockCardLike, MockDrawFrom<MockCardLike>> for FakeHand {
     }
 }
 
+impl Split for FakeHand {
+    fn split(&mut self) -> Self {
+        self.1 = true;
+        FakeHand(self.0.pop().into_iter().collect(), true)
+    }
+}
+
 impl Score for FakeHand {
     fn score(&self) -> Value {
         let mut points = 0;
@@ -72,7 +86,8 @@ impl Score for FakeHand {
         match points.cmp(&{ 21 }) {
             Ordering::Less => Value::Points(points, aces > 0),
             Ordering::Greater => Value::Bust,
-            Ordering::Equal => Value::Blackjack,
+            Ordering::Equal if self.0.len() == 2 && !self.1 => Value::Blackjack,
+            Ordering::Equal => Value::Points(points, aces > 0),
         }
     }
 }
@@ -0,0 +1,68 @@
+use blackjack::backend::card::Card;
+use blackjack::backend::hand::Hand;
+use blackjack::behaviour::interface::{Action, Event, Interface};
+
+/// Interface which plays scripted wagers, actions and answers to playing again, and records the
+/// events it is sent. Once the wagers run out it wagers the minimum, and once the answers run
+/// out it stops playing.
+pub struct ScriptedInterface {
+    pub wagers: Vec<u32>,
+    pub actions: Vec<Action>,
+    pub play_again: Vec<bool>,
+    pub events: Vec<String>,
+}
+
+impl ScriptedInterface {
+    pub fn new(actions: Vec<Action>) -> ScriptedInterface {
+        ScriptedInterface {
+            wagers: vec![],
+            actions,
+            play_again: vec![],
+            events: vec![],
+        }
+    }
+
+    pub fn with_wagers(self, wagers: Vec<u32>) -> ScriptedInterface {
+        ScriptedInterface { wagers, ..self }
+    }
+
+    pub fn with_play_again(self, play_again: Vec<bool>) -> ScriptedInterface {
+        ScriptedInterface { play_again, ..self }
+    }
+}
+
+impl Interface<Hand<Card>> for ScriptedInterface {
+    fn get_wager(&mut self, min: u32, _max: u32) -> u32 {
+        if self.wagers.is_empty() {
+            min
+        } else {
+            self.wagers.remove(0)
+        }
+    }
+
+    fn get_action(&mut self, _actions: &[Action]) -> Action {
+        self.actions.remove(0)
+    }
+
+    fn get_insurance(&mut self, _max: u32) -> u32 {
+        0
+    }
+
+    fn get_even_money(&mut self) -> bool {
+        false
+    }
+
+    fn play_again(&mut self) -> bool {
+        !self.play_again.is_empty() && self.play_again.remove(0)
+    }
+
+    fn send(&mut self, event: Event<Hand<Card>>) {
+        self.events.push(match event {
+            Event::PlayerHand(hand) => format!("PlayerHand({})", hand),
+            Event::DealerHand(hand) => format!("DealerHand({})", hand),
+            Event::DealerReveal(hand) => format!("DealerReveal({})", hand),
+            Event::DealerUpcard(card) => format!("DealerUpcard({})", card),
+            event => format!("{:?}", event),
+        });
+    }
+}
@@ -1 +1,3 @@
+pub mod deck;
 pub mod hand;
+pub mod interface;
@@ -0,0 +1,43 @@
+use crate::fake::interface::ScriptedInterface;
+use blackjack::backend::bankroll::Bankroll;
+use blackjack::backend::blackjack::Blackjack;
+use blackjack::backend::card::Card;
+use blackjack::backend::deck::Deck;
+use blackjack::backend::hand::Hand;
+use blackjack::backend::rules::Rules;
+use blackjack::backend::shoe::Shoe;
+use blackjack::behaviour::draw::DrawFrom;
+use blackjack::behaviour::interface::Action;
+
+#[test]
+fn play_stacked_deck() {
+    let mut ui = ScriptedInterface::new(vec![Action::PlayerHit, Action::PlayerStay]);
+    let mut deck: Deck = "5H 10S 6D 6C 9S 10D".parse().unwrap();
+    let mut bankroll = Bankroll::new(1000);
+    Blackjack::<_, _, _, Hand<Card>>::new(&mut ui, &mut deck, &mut bankroll, Rules::default())
+        .start();
+    assert!(ui.actions.is_empty());
+    assert!(ui
+        .events
+        .contains(&"DealerUpcard(Ten of Spades)".to_string()));
+    assert!(ui
+        .events
+        .contains(&"PlayerHand(Five of Hearts, Six of Diamonds, Nine of Spades)".to_string()));
+    assert!(ui.events.contains(&"DealerBust".to_string()));
+    assert!(ui.events.contains(&"PlayerWin".to_string()));
+    assert_eq!(1010, bankroll.balance());
+}
+
+#[test]
+fn play_on_when_shoe_runs_out_mid_round() {
+    let mut shoe = Shoe::with_seed(1, 0.75, 3);
+    for _ in 0..50 {
+        shoe.draw().unwrap();
+    }
+    let mut ui = ScriptedInterface::new(vec![Action::PlayerStay; 4]);
+    let mut bankroll = Bankroll::new(1000);
+    Blackjack::<_, _, _, Hand<Card>>::new(&mut ui, &mut shoe, &mut bankroll, Rules::default())
+        .start();
+    assert!(ui.events.last().unwrap().starts_with("Bankroll"));
+    assert!(shoe.remaining() > 40);
+}
@@ -0,0 +1,24 @@
+use blackjack::backend::bankroll::Bankroll;
+use blackjack::backend::blackjack::Blackjack;
+use blackjack::backend::card::Card;
+use blackjack::backend::deck::Deck;
+use blackjack::backend::hand::Hand;
+use blackjack::backend::rules::Rules;
+use blackjack::backend::strategy::Strategy;
+use blackjack::bot::Bot;
+
+#[test]
+fn bot_plays_by_chart() {
+    let rules = Rules::default();
+    // Stand on 12 against a 2, unlike basic strategy.
+    let chart = Strategy::new(rules)
+        .to_string()
+        .replacen("\n12,H,H,S,", "\n12,S,S,S,", 1);
+    let mut bot = Bot::new(chart.parse().unwrap(), 2).with_wager(20);
+    let mut deck: Deck = "TH 2S 2D 9C 8S 6D 5H 5S 7D 9S 10D".parse().unwrap();
+    let mut bankroll = Bankroll::new(1000);
+    Blackjack::<_, _, _, Hand<Card>>::new(&mut bot, &mut deck, &mut bankroll, rules).play();
+    assert_eq!(2, bot.played());
+    // Standing on 12 loses to the dealer's 19, then doubling 11 against a 5 wins on a dealer bust.
+    assert_eq!(1000 - 20 + 40, bankroll.balance());
+}
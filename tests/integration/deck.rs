@@ -1,13 +1,24 @@
-use blackjack::backend::card::Card;
-use blackjack::backend::deck::Deck;
+use blackjack::backend::card::{Card, ParseCardError, Rank, Suit};
+use blackjack::backend::deck::{Deck, ParseDeckError};
 use blackjack::behaviour::draw::DrawFrom;
+use blackjack::behaviour::reshuffle::Reshuffle;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use std::collections::HashSet;
 
+fn draw_all(deck: &mut Deck) -> Vec<Card> {
+    let mut cards = Vec::new();
+    while let Some(card) = deck.draw() {
+        cards.push(card);
+    }
+    cards
+}
+
 #[test]
 fn has_52_cards() {
     let mut deck = Deck::new();
     let mut size = 0;
-    while deck.draw() != None {
+    while deck.draw().is_some() {
         size += 1;
     }
     assert_eq!(size, 52);
@@ -17,12 +28,160 @@ fn has_52_cards() {
 fn has_unique_cards() {
     let mut deck = Deck::new();
     let mut cards: HashSet<Card> = HashSet::new();
-    loop {
-        if let Some(card) = deck.draw() {
-            assert!(!cards.contains(&card));
-            cards.insert(card);
-        } else {
-            break;
-        }
+    while let Some(card) = deck.draw() {
+        assert!(!cards.contains(&card));
+        cards.insert(card);
+    }
+}
+
+#[test]
+fn needs_reshuffle_after_cut_card() {
+    let mut deck = Deck::new();
+    for _ in 0..39 {
+        assert!(!deck.needs_reshuffle());
+        deck.draw().unwrap();
+    }
+    assert!(!deck.needs_reshuffle());
+    deck.draw().unwrap();
+    assert!(deck.needs_reshuffle());
+}
+
+#[test]
+fn reshuffle_gathers_all_cards() {
+    let mut deck = Deck::new();
+    for _ in 0..45 {
+        deck.draw().unwrap();
+    }
+    deck.reshuffle();
+    assert!(!deck.needs_reshuffle());
+    let mut size = 0;
+    while deck.draw().is_some() {
+        size += 1;
     }
+    assert_eq!(size, 52);
+}
+
+#[test]
+fn same_seed_deals_same_order() {
+    let mut deck = Deck::with_seed(42);
+    let mut other_deck = Deck::with_seed(42);
+    assert_eq!(42, deck.seed());
+    assert_eq!(draw_all(&mut deck), draw_all(&mut other_deck));
+}
+
+#[test]
+fn different_seeds_deal_different_orders() {
+    let mut deck = Deck::with_seed(1);
+    let mut other_deck = Deck::with_seed(2);
+    assert_ne!(draw_all(&mut deck), draw_all(&mut other_deck));
+}
+
+#[test]
+fn same_rng_deals_same_order() {
+    let mut deck = Deck::with_rng(&mut StdRng::seed_from_u64(7));
+    let mut other_deck = Deck::with_rng(&mut StdRng::seed_from_u64(7));
+    assert_eq!(deck.seed(), other_deck.seed());
+    assert_eq!(draw_all(&mut deck), draw_all(&mut other_deck));
+}
+
+#[test]
+fn replay_reshuffle_from_its_seed() {
+    let mut deck = Deck::with_seed(42);
+    deck.reshuffle();
+    let mut other_deck = Deck::with_seed(42);
+    other_deck.reshuffle();
+    let mut replayed_deck = Deck::with_seed(deck.seed());
+    assert_ne!(42, deck.seed());
+    assert_eq!(deck.seed(), other_deck.seed());
+    let cards = draw_all(&mut deck);
+    assert_eq!(cards, draw_all(&mut other_deck));
+    assert_eq!(cards, draw_all(&mut replayed_deck));
+}
+
+#[test]
+fn stacked_deck_deals_in_order() {
+    let mut deck = Deck::from_cards(vec![
+        Card::new(Suit::Spade, Rank::Ace),
+        Card::new(Suit::Heart, Rank::King),
+    ]);
+    assert_eq!(Some(Card::new(Suit::Spade, Rank::Ace)), deck.draw());
+    assert_eq!(Some(Card::new(Suit::Heart, Rank::King)), deck.draw());
+    assert_eq!(None, deck.draw());
+}
+
+#[test]
+fn stacked_deck_reshuffles_its_own_cards() {
+    let mut deck = Deck::from_cards(vec![
+        Card::new(Suit::Spade, Rank::Ace),
+        Card::new(Suit::Heart, Rank::King),
+    ]);
+    draw_all(&mut deck);
+    assert!(deck.needs_reshuffle());
+    deck.reshuffle();
+    let cards: HashSet<Card> = draw_all(&mut deck).into_iter().collect();
+    assert_eq!(2, cards.len());
+    assert!(cards.contains(&Card::new(Suit::Spade, Rank::Ace)));
+    assert!(cards.contains(&Card::new(Suit::Heart, Rank::King)));
+}
+
+#[test]
+fn parse_stacked_deck() {
+    let mut deck: Deck = "AS KH 10D 5c".parse().unwrap();
+    assert_eq!(
+        vec![
+            Card::new(Suit::Spade, Rank::Ace),
+            Card::new(Suit::Heart, Rank::King),
+            Card::new(Suit::Diamond, Rank::Ten),
+            Card::new(Suit::Club, Rank::Five),
+        ],
+        draw_all(&mut deck)
+    );
+}
+
+#[test]
+fn parse_stacked_deck_with_bad_card() {
+    assert_eq!(
+        ParseDeckError(
+            "11H".to_string(),
+            ParseCardError::InvalidRank("11".to_string())
+        ),
+        "AS 11H 5C".parse::<Deck>().unwrap_err()
+    );
+    assert_eq!(
+        ParseDeckError(
+            "K".to_string(),
+            ParseCardError::InvalidSuit("K".to_string())
+        ),
+        "AS K".parse::<Deck>().unwrap_err()
+    );
+    assert_eq!(
+        ParseDeckError(
+            "0S".to_string(),
+            ParseCardError::InvalidRank("0".to_string())
+        ),
+        "0S".parse::<Deck>().unwrap_err()
+    );
+}
+
+#[test]
+fn reshuffle_discards_leaves_cards_in_play() {
+    let mut deck: Deck = "AS KH 5D 9C".parse().unwrap();
+    let in_play = [deck.draw().unwrap(), deck.draw().unwrap()];
+    draw_all(&mut deck);
+    deck.reshuffle_discards(&[&in_play[0], &in_play[1]]);
+    let cards: HashSet<Card> = draw_all(&mut deck).into_iter().collect();
+    assert_eq!(2, cards.len());
+    assert!(cards.contains(&Card::new(Suit::Diamond, Rank::Five)));
+    assert!(cards.contains(&Card::new(Suit::Club, Rank::Nine)));
+}
+
+#[test]
+fn parse_stacked_deck_with_symbols() {
+    let mut deck: Deck = "A♠ 10♥".parse().unwrap();
+    assert_eq!(Some(Card::new(Suit::Spade, Rank::Ace)), deck.draw());
+    assert_eq!(Some(Card::new(Suit::Heart, Rank::Ten)), deck.draw());
+    assert_eq!(
+        ParseDeckError("♠".to_string(), ParseCardError::InvalidRank(String::new())),
+        "♠".parse::<Deck>().unwrap_err()
+    );
 }
@@ -44,7 +44,7 @@ fn score_one_card() {
     if let Points(points, _) = hand.score() {
         assert!(points > 0);
     } else {
-        assert!(false)
+        panic!("Expected points for a single card.")
     }
 }
 
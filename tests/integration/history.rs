@@ -0,0 +1,171 @@
+use crate::fake::interface::ScriptedInterface;
+use blackjack::backend::bankroll::Bankroll;
+use blackjack::backend::card::{Card, Rank, Suit};
+use blackjack::backend::deck::Deck;
+use blackjack::backend::history::{History, ParseHistoryError, ReplayError, Step};
+use blackjack::backend::rules::{Rules, Surrender};
+use blackjack::behaviour::interface::{Action, OwnedEvent};
+
+fn record() -> History {
+    let mut ui =
+        ScriptedInterface::new(vec![Action::PlayerHit, Action::PlayerStay]).with_wagers(vec![20]);
+    let mut deck: Deck = "5H 10S 6D 6C 9S 10D 2C".parse().unwrap();
+    let rules = Rules {
+        surrender: Surrender::Late,
+        ..Rules::default()
+    };
+    History::record(&mut ui, &mut deck, &mut Bankroll::new(1000), rules)
+}
+
+#[test]
+fn record_round() {
+    let history = record();
+    let dealt: Vec<Card> = "5H 10S 6D 6C 9S 10D"
+        .split_whitespace()
+        .map(|c| c.parse().unwrap())
+        .collect();
+    assert_eq!(dealt, history.cards);
+    assert_eq!(1000, history.bankroll);
+    assert_eq!(Surrender::Late, history.rules.surrender);
+    assert_eq!(Some(&Step::Wager(20)), history.steps.first());
+    assert!(history.steps.contains(&Step::Action(Action::PlayerHit)));
+    assert!(history
+        .steps
+        .contains(&Step::Event(OwnedEvent::DealerUpcard(Card::new(
+            Suit::Spade,
+            Rank::Ten
+        )))));
+    assert_eq!(
+        Some(&Step::Event(OwnedEvent::Bankroll(1020))),
+        history.steps.last()
+    );
+}
+
+#[test]
+fn record_session() {
+    let mut ui = ScriptedInterface::new(vec![
+        Action::PlayerHit,
+        Action::PlayerStay,
+        Action::PlayerStay,
+    ])
+    .with_play_again(vec![true, false]);
+    let mut deck: Deck = "5H 10S 6D 6C 9S 10D 10H 9C 8D 7S 2C 3C".parse().unwrap();
+    let mut histories = Vec::new();
+    History::record_session(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules::default(),
+        |history| histories.push(history),
+    );
+    assert_eq!(2, histories.len());
+    assert_eq!(1000, histories[0].bankroll);
+    assert_eq!(1010, histories[1].bankroll);
+    assert_eq!(6, histories[0].cards.len());
+    assert_eq!(5, histories[1].cards.len());
+    for history in &histories {
+        assert_eq!(Ok(()), history.replay(&mut ScriptedInterface::new(vec![])));
+    }
+}
+
+#[test]
+fn replay_round() {
+    let history = record();
+    let mut ui = ScriptedInterface::new(vec![]);
+    assert_eq!(Ok(()), history.replay(&mut ui));
+    let events = history
+        .steps
+        .iter()
+        .filter(|s| matches!(s, Step::Event(_)))
+        .count();
+    assert_eq!(events, ui.events.len());
+}
+
+#[test]
+fn replay_rejected_decisions() {
+    let actions = vec![Action::PlayerSplit, Action::PlayerHit, Action::PlayerStay];
+    let mut ui = ScriptedInterface::new(actions).with_wagers(vec![5, 20]);
+    let mut deck: Deck = "5H 10S 6D 6C 9S 10D".parse().unwrap();
+    let history = History::record(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules::default(),
+    );
+    assert_eq!([Step::Wager(5), Step::Wager(20)], history.steps[..2]);
+    assert!(history.steps.contains(&Step::Action(Action::PlayerSplit)));
+    assert_eq!(Ok(()), history.replay(&mut ScriptedInterface::new(vec![])));
+}
+
+#[test]
+fn replay_detects_changed_outcome() {
+    let mut history = record();
+    let last = history.steps.len() - 1;
+    history.steps[last] = Step::Event(OwnedEvent::Bankroll(1040));
+    assert_eq!(
+        Err(ReplayError::Event {
+            step: last,
+            expected: "event bankroll 1040".to_string(),
+            actual: "event bankroll 1020".to_string(),
+        }),
+        history.replay(&mut ScriptedInterface::new(vec![]))
+    );
+}
+
+#[test]
+fn replay_detects_changed_decision() {
+    let mut history = record();
+    let hit = history
+        .steps
+        .iter()
+        .position(|s| *s == Step::Action(Action::PlayerHit))
+        .unwrap();
+    history.steps[hit] = Step::Wager(20);
+    assert_eq!(
+        Err(ReplayError::Decision(hit)),
+        history.replay(&mut ScriptedInterface::new(vec![]))
+    );
+}
+
+#[test]
+fn replay_detects_missing_cards() {
+    let mut history = record();
+    history.cards.truncate(3);
+    assert_eq!(
+        Err(ReplayError::OutOfCards(4)),
+        history.replay(&mut ScriptedInterface::new(vec![]))
+    );
+    let history: History = "bankroll 1000\ncards AS KH\nwager 10\n".parse().unwrap();
+    assert!(history.replay(&mut ScriptedInterface::new(vec![])).is_err());
+}
+
+#[test]
+fn replay_detects_missing_steps() {
+    let mut history = record();
+    history.steps.push(Step::Event(OwnedEvent::Tie));
+    assert!(history.replay(&mut ScriptedInterface::new(vec![])).is_err());
+}
+
+#[test]
+fn write_and_read_history() {
+    let history = record();
+    let text = history.to_string();
+    assert!(text.contains("surrender=late"));
+    assert!(text.contains("\nbankroll 1000\ncards 5H TS 6D 6C 9S TD\nwager 20\n"));
+    assert!(text.contains("action hit\n"));
+    assert!(text.contains("event dealer_upcard TS\n"));
+    assert_eq!(history, text.parse().unwrap());
+}
+
+#[test]
+fn read_history_with_bad_line() {
+    let text = "bankroll 1000\ncards AS KH\naction fold\n";
+    assert_eq!(
+        Err(ParseHistoryError("action fold".to_string())),
+        text.parse::<History>()
+    );
+    assert_eq!(
+        Err(ParseHistoryError("rules decks=one".to_string())),
+        "rules decks=one".parse::<History>()
+    );
+}
@@ -1,2 +1,7 @@
+mod blackjack;
+mod bot;
 mod deck;
 mod hand;
+mod history;
+mod shoe;
+mod simulation;
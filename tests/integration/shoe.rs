@@ -0,0 +1,84 @@
+use blackjack::backend::card::Card;
+use blackjack::backend::shoe::Shoe;
+use blackjack::behaviour::draw::DrawFrom;
+use blackjack::behaviour::reshuffle::Reshuffle;
+use std::collections::HashMap;
+
+#[test]
+fn has_312_cards_with_six_decks() {
+    let mut shoe = Shoe::new(6, 0.75);
+    assert_eq!(312, shoe.size());
+    let mut size = 0;
+    while shoe.draw().is_some() {
+        size += 1;
+    }
+    assert_eq!(size, 312);
+    assert_eq!(0, shoe.remaining());
+}
+
+#[test]
+fn has_each_card_once_per_deck() {
+    let mut shoe = Shoe::new(8, 0.75);
+    let mut cards: HashMap<Card, usize> = HashMap::new();
+    while let Some(card) = shoe.draw() {
+        *cards.entry(card).or_default() += 1;
+    }
+    assert_eq!(52, cards.len());
+    assert!(cards.values().all(|count| *count == 8));
+}
+
+#[test]
+fn tracks_penetration() {
+    let mut shoe = Shoe::new(2, 0.5);
+    assert_eq!(52, shoe.cut_card());
+    for _ in 0..26 {
+        shoe.draw().unwrap();
+    }
+    assert_eq!(0.25, shoe.penetration());
+    assert_eq!(78, shoe.remaining());
+}
+
+#[test]
+fn needs_reshuffle_at_cut_card() {
+    let mut shoe = Shoe::new(1, 0.75);
+    for _ in 0..39 {
+        assert!(!shoe.needs_reshuffle());
+        shoe.draw().unwrap();
+    }
+    assert!(shoe.needs_reshuffle());
+}
+
+#[test]
+fn reshuffle_gathers_all_cards() {
+    let mut shoe = Shoe::new(6, 0.75);
+    for _ in 0..250 {
+        shoe.draw().unwrap();
+    }
+    shoe.reshuffle();
+    assert!(!shoe.needs_reshuffle());
+    assert_eq!(0.0, shoe.penetration());
+    assert_eq!(312, shoe.remaining());
+}
+
+#[test]
+fn same_seed_deals_same_order() {
+    let mut shoe = Shoe::with_seed(6, 0.75, 42);
+    let mut other_shoe = Shoe::with_seed(6, 0.75, 42);
+    assert_eq!(42, shoe.seed());
+    while let Some(card) = shoe.draw() {
+        assert_eq!(Some(card), other_shoe.draw());
+    }
+}
+
+#[test]
+fn clamps_penetration() {
+    assert_eq!(104, Shoe::new(2, 1.5).cut_card());
+    assert_eq!(0, Shoe::new(2, -0.5).cut_card());
+    assert!(Shoe::new(2, -0.5).needs_reshuffle());
+}
+
+#[test]
+#[should_panic(expected = "a shoe needs at least one deck")]
+fn reject_no_decks() {
+    Shoe::new(0, 0.75);
+}
@@ -0,0 +1,96 @@
+use blackjack::backend::rules::Rules;
+use blackjack::backend::strategy::Strategy;
+use blackjack::simulation::Simulation;
+
+fn basic_strategy() -> Simulation {
+    let rules = Rules::default();
+    Simulation::new(Strategy::new(rules), rules).with_seed(7)
+}
+
+#[test]
+fn plays_every_round() {
+    let statistics = basic_strategy().run(1000);
+    assert_eq!(1000, statistics.rounds());
+    assert!(statistics.hands() >= 1000);
+    let rates = statistics.win_rate()
+        + statistics.loss_rate()
+        + statistics.push_rate()
+        + statistics.surrender_rate();
+    assert!((rates - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn ev_per_round_and_per_hand() {
+    let statistics = basic_strategy().run(1000);
+    let net = statistics.net() as f64 / statistics.wager() as f64;
+    assert!((statistics.ev() * 1000.0 - net).abs() < 1e-9);
+    assert!((statistics.ev_per_hand() * statistics.hands() as f64 - net).abs() < 1e-9);
+    let text = statistics.to_string();
+    assert!(text.contains(&format!("EV per round: {:+.4}%", statistics.ev() * 100.0)));
+    assert!(text.contains(&format!(
+        "EV per hand: {:+.4}%",
+        statistics.ev_per_hand() * 100.0
+    )));
+}
+
+#[test]
+fn same_seed_gives_same_results() {
+    assert_eq!(basic_strategy().run(2000), basic_strategy().run(2000));
+    assert_ne!(
+        basic_strategy().run(2000),
+        basic_strategy().with_seed(8).run(2000)
+    );
+}
+
+#[test]
+fn basic_strategy_is_close_to_even() {
+    let statistics = basic_strategy().run(100_000);
+    let (low, high) = statistics.confidence_interval();
+    assert!(low < statistics.ev() && statistics.ev() < high);
+    assert!(statistics.ev().abs() < 0.03);
+    assert!((1.0..1.3).contains(&statistics.std_dev()));
+    assert!((0.04..0.055).contains(&statistics.blackjack_rate()));
+}
+
+#[test]
+fn always_standing_loses() {
+    let rules = Rules::default();
+    let chart: String = Strategy::new(rules)
+        .to_string()
+        .lines()
+        .map(|line| match line.split_once(',') {
+            Some((label, cells)) if label.parse::<u8>().is_ok() || label == "A" => {
+                format!(
+                    "{},{}\n",
+                    label,
+                    cells.split(',').map(|_| "S").collect::<Vec<_>>().join(",")
+                )
+            }
+            _ => format!("{}\n", line),
+        })
+        .collect();
+    let statistics = Simulation::new(chart.parse().unwrap(), rules)
+        .with_seed(7)
+        .run(100_000);
+    assert!(statistics.ev() < -0.1);
+    assert_eq!(0.0, statistics.surrender_rate());
+}
+
+#[test]
+fn parallel_runs_are_reproducible() {
+    let statistics = basic_strategy().run_parallel(3001, 4);
+    assert_eq!(3001, statistics.rounds());
+    assert_eq!(statistics, basic_strategy().run_parallel(3001, 4));
+    assert_ne!(statistics, basic_strategy().run_parallel(3001, 3));
+}
+
+#[test]
+fn merge_adds_up_results() {
+    let first = basic_strategy().run(500);
+    let second = basic_strategy().with_seed(8).run(700);
+    let mut merged = first;
+    merged.merge(&second);
+    assert_eq!(1200, merged.rounds());
+    assert_eq!(first.hands() + second.hands(), merged.hands());
+    assert_eq!(first.net() + second.net(), merged.net());
+}
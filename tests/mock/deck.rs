@@ -5,7 +5,7 @@ use blackjack::behaviour::draw::MockDrawFrom;
 
 pub fn mock_deck(cards: Vec<(Rank, Suit)>) -> MockDrawFrom<MockCardLike> {
     let mut deck = MockDrawFrom::new();
-    let mut mock_cards: Vec<MockCardLike> = cards.iter().rev().map(|c| mock_card(c)).collect();
+    let mut mock_cards: Vec<MockCardLike> = cards.iter().rev().map(mock_card).collect();
     deck.expect_draw().returning(move || mock_cards.pop());
     deck
 }
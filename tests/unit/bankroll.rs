@@ -0,0 +1,24 @@
+use blackjack::backend::bankroll::{Bankroll, InsufficientFunds};
+
+#[test]
+fn withdraw_and_deposit() {
+    let mut bankroll = Bankroll::new(100);
+    bankroll.withdraw(30).unwrap();
+    assert_eq!(70, bankroll.balance());
+    bankroll.deposit(45);
+    assert_eq!(115, bankroll.balance());
+}
+
+#[test]
+fn withdraw_all() {
+    let mut bankroll = Bankroll::new(100);
+    bankroll.withdraw(100).unwrap();
+    assert_eq!(0, bankroll.balance());
+}
+
+#[test]
+fn withdraw_more_than_balance() {
+    let mut bankroll = Bankroll::new(100);
+    assert_eq!(Err(InsufficientFunds), bankroll.withdraw(101));
+    assert_eq!(100, bankroll.balance());
+}
@@ -1,15 +1,28 @@
+use crate::fake::deck::FakeDeck;
 use crate::fake::hand::FakeHand;
 use crate::mock::deck::mock_deck;
+use blackjack::backend::bankroll::Bankroll;
 use blackjack::backend::blackjack::Blackjack;
 use blackjack::backend::card::{Rank, Suit};
+use blackjack::backend::rules::{Double, Payout, Rules, Surrender};
 use blackjack::behaviour::card_iter::CardIter;
 use blackjack::behaviour::card_like::CardLike;
 use blackjack::behaviour::interface::{Action, Event, Interface};
 
 struct MockInterface {
     player_actions: Vec<Action>,
+    play_again: Vec<bool>,
+    offered_actions: Vec<Vec<Action>>,
+    wager: u32,
+    insurance: u32,
+    take_even_money: bool,
+    insurance_offers: usize,
+    even_money_offers: usize,
     player_bust: usize,
     player_blackjack: usize,
+    player_double: usize,
+    player_split: usize,
+    current_hands: Vec<usize>,
     dealer_bust: usize,
     dealer_blackjack: usize,
     dealer_stay: usize,
@@ -17,16 +30,33 @@ struct MockInterface {
     player_win: usize,
     player_loose: usize,
     tie: usize,
+    player_surrender: usize,
+    player_even_money: usize,
+    insurance_win: usize,
+    insurance_loose: usize,
+    bankrolls: Vec<u32>,
+    reshuffle: usize,
     player_hands: Vec<Vec<(Rank, Suit)>>,
     dealer_hands: Vec<Vec<(Rank, Suit)>>,
+    dealer_upcards: Vec<(Rank, Suit)>,
 }
 
 impl MockInterface {
     fn new() -> MockInterface {
         MockInterface {
             player_actions: vec![],
+            play_again: vec![],
+            offered_actions: vec![],
+            wager: 10,
+            insurance: 0,
+            take_even_money: false,
+            insurance_offers: 0,
+            even_money_offers: 0,
             player_bust: 0,
             player_blackjack: 0,
+            player_double: 0,
+            player_split: 0,
+            current_hands: vec![],
             dealer_bust: 0,
             dealer_blackjack: 0,
             dealer_stay: 0,
@@ -34,8 +64,15 @@ impl MockInterface {
             player_win: 0,
             player_loose: 0,
             tie: 0,
+            player_surrender: 0,
+            player_even_money: 0,
+            insurance_win: 0,
+            insurance_loose: 0,
+            bankrolls: vec![],
+            reshuffle: 0,
             player_hands: vec![],
             dealer_hands: vec![],
+            dealer_upcards: vec![],
         }
     }
 
@@ -44,6 +81,31 @@ impl MockInterface {
         self.player_actions.reverse();
     }
 
+    fn set_play_again(&mut self, play_again: Vec<bool>) {
+        self.play_again = play_again;
+        self.play_again.reverse();
+    }
+
+    fn set_insurance(&mut self, insurance: u32) {
+        self.insurance = insurance;
+    }
+
+    fn set_take_even_money(&mut self, take: bool) {
+        self.take_even_money = take;
+    }
+
+    fn verify_insurance_offers(&self, times: usize) {
+        assert_eq!(times, self.insurance_offers)
+    }
+
+    fn verify_even_money_offers(&self, times: usize) {
+        assert_eq!(times, self.even_money_offers)
+    }
+
+    fn verify_offered_actions(&self, offered: Vec<Vec<Action>>) {
+        assert_eq!(self.offered_actions, offered)
+    }
+
     fn verify_player_bust_times(&self, times: usize) {
         assert_eq!(times, self.player_bust)
     }
@@ -52,6 +114,18 @@ impl MockInterface {
         assert_eq!(times, self.player_blackjack)
     }
 
+    fn verify_player_double_times(&self, times: usize) {
+        assert_eq!(times, self.player_double)
+    }
+
+    fn verify_player_split_times(&self, times: usize) {
+        assert_eq!(times, self.player_split)
+    }
+
+    fn verify_current_hands(&self, hands: Vec<usize>) {
+        assert_eq!(self.current_hands, hands)
+    }
+
     fn verify_dealer_bust_times(&self, times: usize) {
         assert_eq!(times, self.dealer_bust)
     }
@@ -80,6 +154,30 @@ impl MockInterface {
         assert_eq!(times, self.tie)
     }
 
+    fn verify_player_surrender_times(&self, times: usize) {
+        assert_eq!(times, self.player_surrender)
+    }
+
+    fn verify_player_even_money_times(&self, times: usize) {
+        assert_eq!(times, self.player_even_money)
+    }
+
+    fn verify_insurance_win_times(&self, times: usize) {
+        assert_eq!(times, self.insurance_win)
+    }
+
+    fn verify_insurance_loose_times(&self, times: usize) {
+        assert_eq!(times, self.insurance_loose)
+    }
+
+    fn verify_bankrolls(&self, bankrolls: Vec<u32>) {
+        assert_eq!(self.bankrolls, bankrolls)
+    }
+
+    fn verify_reshuffle_times(&self, times: usize) {
+        assert_eq!(times, self.reshuffle)
+    }
+
     fn verify_player_hands(&self, hands: Vec<Vec<(Rank, Suit)>>) {
         assert_eq!(self.player_hands, hands)
     }
@@ -87,17 +185,43 @@ impl MockInterface {
     fn verify_dealer_hands(&self, hands: Vec<Vec<(Rank, Suit)>>) {
         assert_eq!(self.dealer_hands, hands)
     }
+
+    fn verify_dealer_upcards(&self, upcards: Vec<(Rank, Suit)>) {
+        assert_eq!(self.dealer_upcards, upcards)
+    }
 }
 
 impl Interface<FakeHand> for MockInterface {
-    fn get_action(&mut self) -> Action {
+    fn get_wager(&mut self, _min: u32, _max: u32) -> u32 {
+        self.wager
+    }
+
+    fn get_action(&mut self, actions: &[Action]) -> Action {
+        self.offered_actions.push(actions.to_vec());
         self.player_actions.pop().unwrap()
     }
 
+    fn get_insurance(&mut self, _max: u32) -> u32 {
+        self.insurance_offers += 1;
+        self.insurance
+    }
+
+    fn get_even_money(&mut self) -> bool {
+        self.even_money_offers += 1;
+        self.take_even_money
+    }
+
+    fn play_again(&mut self) -> bool {
+        self.play_again.pop().unwrap()
+    }
+
     fn send(&mut self, event: Event<FakeHand>) {
         match event {
             Event::PlayerBust => self.player_bust += 1,
             Event::PlayerBlackjack => self.player_blackjack += 1,
+            Event::PlayerDouble => self.player_double += 1,
+            Event::PlayerSplit => self.player_split += 1,
+            Event::CurrentHand(index) => self.current_hands.push(index),
             Event::DealerBust => self.dealer_bust += 1,
             Event::DealerBlackjack => self.dealer_blackjack += 1,
             Event::DealerStay => self.dealer_stay += 1,
@@ -105,12 +229,21 @@ impl Interface<FakeHand> for MockInterface {
             Event::PlayerWin => self.player_win += 1,
             Event::PlayerLoose => self.player_loose += 1,
             Event::Tie => self.tie += 1,
+            Event::PlayerSurrender => self.player_surrender += 1,
+            Event::PlayerEvenMoney => self.player_even_money += 1,
+            Event::InsuranceWin => self.insurance_win += 1,
+            Event::InsuranceLoose => self.insurance_loose += 1,
+            Event::Bankroll(balance) => self.bankrolls.push(balance),
+            Event::Reshuffle => self.reshuffle += 1,
             Event::PlayerHand(hand) => self
                 .player_hands
                 .push(hand.iter().map(|c| (c.get_rank(), c.get_suit())).collect()),
-            Event::DealerHand(hand) => self
+            Event::DealerHand(hand) | Event::DealerReveal(hand) => self
                 .dealer_hands
                 .push(hand.iter().map(|c| (c.get_rank(), c.get_suit())).collect()),
+            Event::DealerUpcard(card) => {
+                self.dealer_upcards.push((card.get_rank(), card.get_suit()))
+            }
         }
     }
 }
@@ -118,38 +251,39 @@ impl Interface<FakeHand> for MockInterface {
 #[test]
 fn player_six_five_nine_stay_dealer_two_six_jack() {
     let card1 = (Rank::Six, Suit::Diamond);
-    let card2 = (Rank::Five, Suit::Club);
-    let card3 = (Rank::Nine, Suit::Spade);
-    let card4 = (Rank::Two, Suit::Heart);
-    let card5 = (Rank::Six, Suit::Diamond);
+    let card2 = (Rank::Two, Suit::Heart);
+    let card3 = (Rank::Five, Suit::Club);
+    let card4 = (Rank::Six, Suit::Diamond);
+    let card5 = (Rank::Nine, Suit::Spade);
     let card6 = (Rank::Jack, Suit::Diamond);
-    let actions = vec![Action::PlayerHit, Action::PlayerHit, Action::PlayerStay];
+    let actions = vec![Action::PlayerHit, Action::PlayerStay];
     let mut ui = MockInterface::new();
     ui.set_player_actions(actions);
     let mut deck = mock_deck(vec![card1, card2, card3, card4, card5, card6]);
     Blackjack::new(
         &mut ui,
         &mut deck,
-        &mut FakeHand::new(),
-        &mut FakeHand::new(),
+        &mut Bankroll::new(1000),
+        Rules::default(),
     )
     .start();
     ui.verify_player_hands(vec![
         vec![card1],
-        vec![card1, card2],
-        vec![card1, card2, card3],
+        vec![card1, card3],
+        vec![card1, card3, card5],
     ]);
     ui.verify_dealer_hands(vec![
-        vec![card4],
-        vec![card4, card5],
-        vec![card4, card5, card6],
+        vec![card2],
+        vec![card2, card4],
+        vec![card2, card4, card6],
     ]);
+    ui.verify_dealer_upcards(vec![card2]);
     ui.verify_player_bust_times(0);
     ui.verify_player_blackjack_times(0);
     ui.verify_dealer_bust_times(0);
     ui.verify_dealer_blackjack_times(0);
     ui.verify_dealer_stay_times(1);
-    ui.verify_dealer_hit_times(2);
+    ui.verify_dealer_hit_times(1);
     ui.verify_player_win_times(1);
     ui.verify_player_loose_times(0);
     ui.verify_tie_times(0);
@@ -158,73 +292,78 @@ fn player_six_five_nine_stay_dealer_two_six_jack() {
 #[test]
 fn player_king_seven_ten_bust_dealer_ace_jack() {
     let card1 = (Rank::King, Suit::Diamond);
-    let card2 = (Rank::Seven, Suit::Club);
-    let card3 = (Rank::Ten, Suit::Spade);
-    let card4 = (Rank::Ace, Suit::Heart);
-    let card5 = (Rank::Jack, Suit::Diamond);
-    let actions = vec![Action::PlayerHit, Action::PlayerHit];
+    let card2 = (Rank::Ace, Suit::Heart);
+    let card3 = (Rank::Seven, Suit::Club);
+    let card4 = (Rank::Jack, Suit::Diamond);
+    let card5 = (Rank::Ten, Suit::Spade);
+    let actions = vec![Action::PlayerHit];
     let mut ui = MockInterface::new();
     ui.set_player_actions(actions);
     let mut deck = mock_deck(vec![card1, card2, card3, card4, card5]);
     Blackjack::new(
         &mut ui,
         &mut deck,
-        &mut FakeHand::new(),
-        &mut FakeHand::new(),
+        &mut Bankroll::new(1000),
+        Rules {
+            peek: false,
+            ..Rules::default()
+        },
     )
     .start();
     ui.verify_player_hands(vec![
         vec![card1],
-        vec![card1, card2],
-        vec![card1, card2, card3],
+        vec![card1, card3],
+        vec![card1, card3, card5],
     ]);
-    ui.verify_dealer_hands(vec![vec![card4], vec![card4, card5]]);
+    ui.verify_dealer_hands(vec![vec![card2], vec![card2, card4]]);
+    ui.verify_dealer_upcards(vec![card2]);
     ui.verify_player_bust_times(1);
     ui.verify_player_blackjack_times(0);
     ui.verify_dealer_bust_times(0);
     ui.verify_dealer_blackjack_times(1);
     ui.verify_dealer_stay_times(0);
-    ui.verify_dealer_hit_times(1);
+    ui.verify_dealer_hit_times(0);
     ui.verify_player_win_times(0);
     ui.verify_player_loose_times(1);
     ui.verify_tie_times(0);
 }
 
 #[test]
-fn player_king_seven_four_blackjack_dealer_six_eight_jack() {
+fn player_king_seven_four_twenty_one_dealer_six_eight_jack() {
     let card1 = (Rank::King, Suit::Diamond);
-    let card2 = (Rank::Seven, Suit::Club);
-    let card3 = (Rank::Four, Suit::Club);
-    let card4 = (Rank::Six, Suit::Spade);
-    let card5 = (Rank::Eight, Suit::Heart);
+    let card2 = (Rank::Six, Suit::Spade);
+    let card3 = (Rank::Seven, Suit::Club);
+    let card4 = (Rank::Eight, Suit::Heart);
+    let card5 = (Rank::Four, Suit::Club);
     let card6 = (Rank::Jack, Suit::Diamond);
-    let actions = vec![Action::PlayerHit, Action::PlayerHit];
+    let actions = vec![Action::PlayerHit];
     let mut ui = MockInterface::new();
     ui.set_player_actions(actions);
     let mut deck = mock_deck(vec![card1, card2, card3, card4, card5, card6]);
     Blackjack::new(
         &mut ui,
         &mut deck,
-        &mut FakeHand::new(),
-        &mut FakeHand::new(),
+        &mut Bankroll::new(1000),
+        Rules::default(),
     )
     .start();
     ui.verify_player_hands(vec![
         vec![card1],
-        vec![card1, card2],
-        vec![card1, card2, card3],
+        vec![card1, card3],
+        vec![card1, card3, card5],
     ]);
     ui.verify_dealer_hands(vec![
-        vec![card4],
-        vec![card4, card5],
-        vec![card4, card5, card6],
+        vec![card2],
+        vec![card2, card4],
+        vec![card2, card4, card6],
     ]);
+    ui.verify_dealer_upcards(vec![card2]);
     ui.verify_player_bust_times(0);
-    ui.verify_player_blackjack_times(1);
+    ui.verify_player_blackjack_times(0);
     ui.verify_dealer_bust_times(1);
     ui.verify_dealer_blackjack_times(0);
     ui.verify_dealer_stay_times(0);
-    ui.verify_dealer_hit_times(2);
+    ui.verify_dealer_hit_times(1);
     ui.verify_player_win_times(1);
     ui.verify_player_loose_times(0);
     ui.verify_tie_times(0);
@@ -233,28 +372,30 @@ fn player_king_seven_four_blackjack_dealer_six_eight_jack() {
 #[test]
 fn player_ace_king_blackjack_dealer_ace_king() {
     let card1 = (Rank::Ace, Suit::Diamond);
-    let card2 = (Rank::King, Suit::Club);
-    let card3 = (Rank::Ace, Suit::Club);
+    let card2 = (Rank::Ace, Suit::Club);
+    let card3 = (Rank::King, Suit::Club);
     let card4 = (Rank::King, Suit::Spade);
-    let actions = vec![Action::PlayerHit, Action::PlayerHit];
     let mut ui = MockInterface::new();
-    ui.set_player_actions(actions);
     let mut deck = mock_deck(vec![card1, card2, card3, card4]);
     Blackjack::new(
         &mut ui,
         &mut deck,
-        &mut FakeHand::new(),
-        &mut FakeHand::new(),
+        &mut Bankroll::new(1000),
+        Rules {
+            peek: false,
+            ..Rules::default()
+        },
     )
     .start();
-    ui.verify_player_hands(vec![vec![card1], vec![card1, card2]]);
-    ui.verify_dealer_hands(vec![vec![card3], vec![card3, card4]]);
+    ui.verify_player_hands(vec![vec![card1], vec![card1, card3]]);
+    ui.verify_dealer_hands(vec![vec![card2], vec![card2, card4]]);
+    ui.verify_dealer_upcards(vec![card2]);
     ui.verify_player_bust_times(0);
     ui.verify_player_blackjack_times(1);
     ui.verify_dealer_bust_times(0);
     ui.verify_dealer_blackjack_times(1);
     ui.verify_dealer_stay_times(0);
-    ui.verify_dealer_hit_times(1);
+    ui.verify_dealer_hit_times(0);
     ui.verify_player_win_times(0);
     ui.verify_player_loose_times(0);
     ui.verify_tie_times(1);
@@ -263,40 +404,41 @@ fn player_ace_king_blackjack_dealer_ace_king() {
 #[test]
 fn player_ace_nine_king_four_dealer_ace_six_queen() {
     let card1 = (Rank::Ace, Suit::Diamond);
-    let card2 = (Rank::Nine, Suit::Club);
-    let card3 = (Rank::King, Suit::Spade);
-    let card4 = (Rank::Four, Suit::Heart);
-    let card5 = (Rank::Ace, Suit::Heart);
-    let card6 = (Rank::Six, Suit::Diamond);
+    let card2 = (Rank::Ace, Suit::Heart);
+    let card3 = (Rank::Nine, Suit::Club);
+    let card4 = (Rank::Six, Suit::Diamond);
+    let card5 = (Rank::King, Suit::Spade);
+    let card6 = (Rank::Four, Suit::Heart);
     let card7 = (Rank::Queen, Suit::Diamond);
-    let actions = vec![Action::PlayerHit, Action::PlayerHit, Action::PlayerHit];
+    let actions = vec![Action::PlayerHit, Action::PlayerHit];
     let mut ui = MockInterface::new();
     ui.set_player_actions(actions);
     let mut deck = mock_deck(vec![card1, card2, card3, card4, card5, card6, card7]);
     Blackjack::new(
         &mut ui,
         &mut deck,
-        &mut FakeHand::new(),
-        &mut FakeHand::new(),
+        &mut Bankroll::new(1000),
+        Rules::default(),
     )
     .start();
     ui.verify_player_hands(vec![
         vec![card1],
-        vec![card1, card2],
-        vec![card1, card2, card3],
-        vec![card1, card2, card3, card4],
+        vec![card1, card3],
+        vec![card1, card3, card5],
+        vec![card1, card3, card5, card6],
     ]);
     ui.verify_dealer_hands(vec![
-        vec![card5],
-        vec![card5, card6],
-        vec![card5, card6, card7],
+        vec![card2],
+        vec![card2, card4],
+        vec![card2, card4, card7],
     ]);
+    ui.verify_dealer_upcards(vec![card2]);
     ui.verify_player_bust_times(1);
     ui.verify_player_blackjack_times(0);
     ui.verify_dealer_bust_times(0);
     ui.verify_dealer_blackjack_times(0);
     ui.verify_dealer_stay_times(1);
-    ui.verify_dealer_hit_times(2);
+    ui.verify_dealer_hit_times(1);
     ui.verify_player_win_times(0);
     ui.verify_player_loose_times(1);
     ui.verify_tie_times(0);
@@ -305,33 +447,34 @@ fn player_ace_nine_king_four_dealer_ace_six_queen() {
 #[test]
 fn player_seven_ten_stay_dealer_six_ten_nine() {
     let card1 = (Rank::Seven, Suit::Diamond);
-    let card2 = (Rank::Ten, Suit::Club);
-    let card3 = (Rank::Six, Suit::Diamond);
+    let card2 = (Rank::Six, Suit::Diamond);
+    let card3 = (Rank::Ten, Suit::Club);
     let card4 = (Rank::Ten, Suit::Club);
     let card5 = (Rank::Nine, Suit::Spade);
-    let actions = vec![Action::PlayerHit, Action::PlayerStay];
+    let actions = vec![Action::PlayerStay];
     let mut ui = MockInterface::new();
     ui.set_player_actions(actions);
     let mut deck = mock_deck(vec![card1, card2, card3, card4, card5]);
     Blackjack::new(
         &mut ui,
         &mut deck,
-        &mut FakeHand::new(),
-        &mut FakeHand::new(),
+        &mut Bankroll::new(1000),
+        Rules::default(),
     )
     .start();
-    ui.verify_player_hands(vec![vec![card1], vec![card1, card2]]);
+    ui.verify_player_hands(vec![vec![card1], vec![card1, card3]]);
     ui.verify_dealer_hands(vec![
-        vec![card3],
-        vec![card3, card4],
-        vec![card3, card4, card5],
+        vec![card2],
+        vec![card2, card4],
+        vec![card2, card4, card5],
     ]);
+    ui.verify_dealer_upcards(vec![card2]);
     ui.verify_player_bust_times(0);
     ui.verify_player_blackjack_times(0);
     ui.verify_dealer_bust_times(1);
     ui.verify_dealer_blackjack_times(0);
     ui.verify_dealer_stay_times(0);
-    ui.verify_dealer_hit_times(2);
+    ui.verify_dealer_hit_times(1);
     ui.verify_player_win_times(1);
     ui.verify_player_loose_times(0);
     ui.verify_tie_times(0);
@@ -340,34 +483,1009 @@ fn player_seven_ten_stay_dealer_six_ten_nine() {
 #[test]
 fn player_two_ten_stay_dealer_two_six_jack() {
     let card1 = (Rank::Two, Suit::Diamond);
-    let card2 = (Rank::Six, Suit::Club);
-    let card3 = (Rank::Two, Suit::Heart);
+    let card2 = (Rank::Two, Suit::Heart);
+    let card3 = (Rank::Six, Suit::Club);
     let card4 = (Rank::Six, Suit::Diamond);
     let card5 = (Rank::Jack, Suit::Diamond);
-    let actions = vec![Action::PlayerHit, Action::PlayerStay];
+    let actions = vec![Action::PlayerStay];
     let mut ui = MockInterface::new();
     ui.set_player_actions(actions);
     let mut deck = mock_deck(vec![card1, card2, card3, card4, card5]);
     Blackjack::new(
         &mut ui,
         &mut deck,
-        &mut FakeHand::new(),
-        &mut FakeHand::new(),
+        &mut Bankroll::new(1000),
+        Rules::default(),
     )
     .start();
-    ui.verify_player_hands(vec![vec![card1], vec![card1, card2]]);
+    ui.verify_player_hands(vec![vec![card1], vec![card1, card3]]);
     ui.verify_dealer_hands(vec![
-        vec![card3],
-        vec![card3, card4],
-        vec![card3, card4, card5],
+        vec![card2],
+        vec![card2, card4],
+        vec![card2, card4, card5],
     ]);
+    ui.verify_dealer_upcards(vec![card2]);
     ui.verify_player_bust_times(0);
     ui.verify_player_blackjack_times(0);
     ui.verify_dealer_bust_times(0);
     ui.verify_dealer_blackjack_times(0);
     ui.verify_dealer_stay_times(1);
-    ui.verify_dealer_hit_times(2);
+    ui.verify_dealer_hit_times(1);
+    ui.verify_player_win_times(0);
+    ui.verify_player_loose_times(1);
+    ui.verify_tie_times(0);
+}
+
+#[test]
+fn player_ace_king_blackjack_dealer_five_six_king() {
+    let card1 = (Rank::Ace, Suit::Diamond);
+    let card2 = (Rank::Five, Suit::Club);
+    let card3 = (Rank::King, Suit::Club);
+    let card4 = (Rank::Six, Suit::Spade);
+    let card5 = (Rank::King, Suit::Heart);
+    let mut ui = MockInterface::new();
+    let mut deck = mock_deck(vec![card1, card2, card3, card4, card5]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules::default(),
+    )
+    .start();
+    ui.verify_player_hands(vec![vec![card1], vec![card1, card3]]);
+    ui.verify_dealer_hands(vec![
+        vec![card2],
+        vec![card2, card4],
+        vec![card2, card4, card5],
+    ]);
+    ui.verify_dealer_upcards(vec![card2]);
+    ui.verify_player_blackjack_times(1);
+    ui.verify_dealer_blackjack_times(0);
+    ui.verify_dealer_stay_times(1);
+    ui.verify_dealer_hit_times(1);
+    ui.verify_player_win_times(1);
+    ui.verify_player_loose_times(0);
+    ui.verify_tie_times(0);
+}
+
+#[test]
+fn player_five_six_king_dealer_ace_king_blackjack() {
+    let card1 = (Rank::Five, Suit::Diamond);
+    let card2 = (Rank::Ace, Suit::Club);
+    let card3 = (Rank::Six, Suit::Club);
+    let card4 = (Rank::King, Suit::Spade);
+    let card5 = (Rank::King, Suit::Heart);
+    let actions = vec![Action::PlayerHit];
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(actions);
+    let mut deck = mock_deck(vec![card1, card2, card3, card4, card5]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules {
+            peek: false,
+            ..Rules::default()
+        },
+    )
+    .start();
+    ui.verify_player_hands(vec![
+        vec![card1],
+        vec![card1, card3],
+        vec![card1, card3, card5],
+    ]);
+    ui.verify_dealer_hands(vec![vec![card2], vec![card2, card4]]);
+    ui.verify_dealer_upcards(vec![card2]);
+    ui.verify_player_blackjack_times(0);
+    ui.verify_dealer_blackjack_times(1);
+    ui.verify_player_win_times(0);
+    ui.verify_player_loose_times(1);
+    ui.verify_tie_times(0);
+}
+
+#[test]
+fn player_ace_four_six_dealer_seven_four_king() {
+    let card1 = (Rank::Ace, Suit::Diamond);
+    let card2 = (Rank::Seven, Suit::Club);
+    let card3 = (Rank::Four, Suit::Club);
+    let card4 = (Rank::Four, Suit::Spade);
+    let card5 = (Rank::Six, Suit::Heart);
+    let card6 = (Rank::King, Suit::Heart);
+    let actions = vec![Action::PlayerHit];
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(actions);
+    let mut deck = mock_deck(vec![card1, card2, card3, card4, card5, card6]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules::default(),
+    )
+    .start();
+    ui.verify_player_hands(vec![
+        vec![card1],
+        vec![card1, card3],
+        vec![card1, card3, card5],
+    ]);
+    ui.verify_dealer_hands(vec![
+        vec![card2],
+        vec![card2, card4],
+        vec![card2, card4, card6],
+    ]);
+    ui.verify_player_blackjack_times(0);
+    ui.verify_dealer_blackjack_times(0);
+    ui.verify_dealer_stay_times(1);
+    ui.verify_player_win_times(0);
+    ui.verify_player_loose_times(0);
+    ui.verify_tie_times(1);
+}
+
+#[test]
+fn player_five_six_double_king_dealer_ten_seven() {
+    let card1 = (Rank::Five, Suit::Diamond);
+    let card2 = (Rank::Ten, Suit::Club);
+    let card3 = (Rank::Six, Suit::Club);
+    let card4 = (Rank::Seven, Suit::Spade);
+    let card5 = (Rank::King, Suit::Heart);
+    let actions = vec![Action::PlayerDouble];
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(actions);
+    let mut deck = mock_deck(vec![card1, card2, card3, card4, card5]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules::default(),
+    )
+    .start();
+    ui.verify_offered_actions(vec![vec![
+        Action::PlayerHit,
+        Action::PlayerStay,
+        Action::PlayerDouble,
+    ]]);
+    ui.verify_player_hands(vec![
+        vec![card1],
+        vec![card1, card3],
+        vec![card1, card3, card5],
+    ]);
+    ui.verify_dealer_hands(vec![vec![card2], vec![card2, card4]]);
+    ui.verify_player_double_times(1);
+    ui.verify_player_bust_times(0);
+    ui.verify_dealer_stay_times(1);
+    ui.verify_player_win_times(1);
+    ui.verify_player_loose_times(0);
+    ui.verify_tie_times(0);
+}
+
+#[test]
+fn player_ten_two_double_king_bust_dealer_ten_seven() {
+    let card1 = (Rank::Ten, Suit::Diamond);
+    let card2 = (Rank::Ten, Suit::Club);
+    let card3 = (Rank::Two, Suit::Club);
+    let card4 = (Rank::Seven, Suit::Spade);
+    let card5 = (Rank::King, Suit::Heart);
+    let actions = vec![Action::PlayerDouble];
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(actions);
+    let mut deck = mock_deck(vec![card1, card2, card3, card4, card5]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules::default(),
+    )
+    .start();
+    ui.verify_player_hands(vec![
+        vec![card1],
+        vec![card1, card3],
+        vec![card1, card3, card5],
+    ]);
+    ui.verify_player_double_times(1);
+    ui.verify_player_bust_times(1);
     ui.verify_player_win_times(0);
     ui.verify_player_loose_times(1);
+}
+
+#[test]
+fn player_two_three_hit_four_cannot_double() {
+    let card1 = (Rank::Two, Suit::Diamond);
+    let card2 = (Rank::Ten, Suit::Club);
+    let card3 = (Rank::Three, Suit::Club);
+    let card4 = (Rank::Seven, Suit::Spade);
+    let card5 = (Rank::Four, Suit::Heart);
+    let actions = vec![Action::PlayerHit, Action::PlayerStay];
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(actions);
+    let mut deck = mock_deck(vec![card1, card2, card3, card4, card5]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules::default(),
+    )
+    .start();
+    ui.verify_offered_actions(vec![
+        vec![Action::PlayerHit, Action::PlayerStay, Action::PlayerDouble],
+        vec![Action::PlayerHit, Action::PlayerStay],
+    ]);
+    ui.verify_player_double_times(0);
+    ui.verify_player_loose_times(1);
+}
+
+#[test]
+fn player_ten_two_cannot_double_on_nine_to_eleven() {
+    let card1 = (Rank::Ten, Suit::Diamond);
+    let card2 = (Rank::Ten, Suit::Club);
+    let card3 = (Rank::Two, Suit::Club);
+    let card4 = (Rank::Seven, Suit::Spade);
+    let actions = vec![Action::PlayerDouble, Action::PlayerStay];
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(actions);
+    let mut deck = mock_deck(vec![card1, card2, card3, card4]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules {
+            double: Double::NineToEleven,
+            ..Rules::default()
+        },
+    )
+    .start();
+    ui.verify_offered_actions(vec![
+        vec![Action::PlayerHit, Action::PlayerStay],
+        vec![Action::PlayerHit, Action::PlayerStay],
+    ]);
+    ui.verify_player_hands(vec![vec![card1], vec![card1, card3]]);
+    ui.verify_player_double_times(0);
+    ui.verify_player_loose_times(1);
+}
+
+#[test]
+fn player_eight_eight_split_dealer_ten_seven() {
+    let card1 = (Rank::Eight, Suit::Diamond);
+    let card2 = (Rank::Ten, Suit::Club);
+    let card3 = (Rank::Eight, Suit::Spade);
+    let card4 = (Rank::Seven, Suit::Spade);
+    let card5 = (Rank::King, Suit::Heart);
+    let card6 = (Rank::Two, Suit::Club);
+    let card7 = (Rank::Nine, Suit::Diamond);
+    let actions = vec![
+        Action::PlayerSplit,
+        Action::PlayerStay,
+        Action::PlayerHit,
+        Action::PlayerStay,
+    ];
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(actions);
+    let mut deck = mock_deck(vec![card1, card2, card3, card4, card5, card6, card7]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules::default(),
+    )
+    .start();
+    ui.verify_offered_actions(vec![
+        vec![
+            Action::PlayerHit,
+            Action::PlayerStay,
+            Action::PlayerDouble,
+            Action::PlayerSplit,
+        ],
+        vec![Action::PlayerHit, Action::PlayerStay, Action::PlayerDouble],
+        vec![Action::PlayerHit, Action::PlayerStay, Action::PlayerDouble],
+        vec![Action::PlayerHit, Action::PlayerStay],
+    ]);
+    ui.verify_player_hands(vec![
+        vec![card1],
+        vec![card1, card3],
+        vec![card1, card5],
+        vec![card3, card6],
+        vec![card3, card6, card7],
+    ]);
+    ui.verify_dealer_hands(vec![vec![card2], vec![card2, card4]]);
+    ui.verify_player_split_times(1);
+    ui.verify_current_hands(vec![0, 1, 0, 1]);
+    ui.verify_dealer_stay_times(1);
+    ui.verify_player_win_times(2);
+    ui.verify_player_loose_times(0);
+    ui.verify_tie_times(0);
+}
+
+#[test]
+fn player_ace_ace_split_gets_one_card_each() {
+    let card1 = (Rank::Ace, Suit::Diamond);
+    let card2 = (Rank::Nine, Suit::Club);
+    let card3 = (Rank::Ace, Suit::Spade);
+    let card4 = (Rank::Eight, Suit::Spade);
+    let card5 = (Rank::King, Suit::Heart);
+    let card6 = (Rank::Ace, Suit::Club);
+    let actions = vec![Action::PlayerSplit];
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(actions);
+    let mut deck = mock_deck(vec![card1, card2, card3, card4, card5, card6]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules::default(),
+    )
+    .start();
+    ui.verify_offered_actions(vec![vec![
+        Action::PlayerHit,
+        Action::PlayerStay,
+        Action::PlayerDouble,
+        Action::PlayerSplit,
+    ]]);
+    ui.verify_player_hands(vec![
+        vec![card1],
+        vec![card1, card3],
+        vec![card1, card5],
+        vec![card3, card6],
+    ]);
+    ui.verify_player_split_times(1);
+    ui.verify_player_blackjack_times(0);
+    ui.verify_current_hands(vec![0, 1, 0, 1]);
+    ui.verify_player_win_times(1);
+    ui.verify_player_loose_times(1);
     ui.verify_tie_times(0);
 }
+
+#[test]
+fn player_eight_eight_split_cannot_resplit_past_max_hands() {
+    let card1 = (Rank::Eight, Suit::Diamond);
+    let card2 = (Rank::Ten, Suit::Club);
+    let card3 = (Rank::Eight, Suit::Spade);
+    let card4 = (Rank::Seven, Suit::Spade);
+    let card5 = (Rank::Eight, Suit::Heart);
+    let card6 = (Rank::Ten, Suit::Heart);
+    let actions = vec![Action::PlayerSplit, Action::PlayerStay, Action::PlayerStay];
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(actions);
+    let mut deck = mock_deck(vec![card1, card2, card3, card4, card5, card6]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules {
+            max_hands: 2,
+            ..Rules::default()
+        },
+    )
+    .start();
+    ui.verify_offered_actions(vec![
+        vec![
+            Action::PlayerHit,
+            Action::PlayerStay,
+            Action::PlayerDouble,
+            Action::PlayerSplit,
+        ],
+        vec![Action::PlayerHit, Action::PlayerStay, Action::PlayerDouble],
+        vec![Action::PlayerHit, Action::PlayerStay, Action::PlayerDouble],
+    ]);
+    ui.verify_player_split_times(1);
+    ui.verify_player_win_times(1);
+    ui.verify_player_loose_times(1);
+    ui.verify_tie_times(0);
+}
+
+#[test]
+fn player_ten_six_late_surrender_dealer_ten_seven() {
+    let card1 = (Rank::Ten, Suit::Diamond);
+    let card2 = (Rank::Ten, Suit::Club);
+    let card3 = (Rank::Six, Suit::Club);
+    let card4 = (Rank::Seven, Suit::Spade);
+    let actions = vec![Action::PlayerSurrender];
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(actions);
+    let mut deck = mock_deck(vec![card1, card2, card3, card4]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules {
+            surrender: Surrender::Late,
+            ..Rules::default()
+        },
+    )
+    .start();
+    ui.verify_offered_actions(vec![vec![
+        Action::PlayerHit,
+        Action::PlayerStay,
+        Action::PlayerDouble,
+        Action::PlayerSurrender,
+    ]]);
+    ui.verify_player_hands(vec![vec![card1], vec![card1, card3]]);
+    ui.verify_player_surrender_times(1);
+    ui.verify_player_win_times(0);
+    ui.verify_player_loose_times(0);
+    ui.verify_tie_times(0);
+}
+
+#[test]
+fn player_ten_six_no_late_surrender_dealer_ace_king_blackjack() {
+    let card1 = (Rank::Ten, Suit::Diamond);
+    let card2 = (Rank::Ace, Suit::Club);
+    let card3 = (Rank::Six, Suit::Club);
+    let card4 = (Rank::King, Suit::Spade);
+    let mut ui = MockInterface::new();
+    let mut deck = mock_deck(vec![card1, card2, card3, card4]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules {
+            surrender: Surrender::Late,
+            ..Rules::default()
+        },
+    )
+    .start();
+    ui.verify_offered_actions(vec![]);
+    ui.verify_player_hands(vec![vec![card1], vec![card1, card3]]);
+    ui.verify_dealer_hands(vec![vec![card2], vec![card2, card4]]);
+    ui.verify_dealer_blackjack_times(1);
+    ui.verify_player_surrender_times(0);
+    ui.verify_player_win_times(0);
+    ui.verify_player_loose_times(1);
+    ui.verify_tie_times(0);
+}
+
+#[test]
+fn player_ten_six_late_surrender_dealer_king_ace_blackjack_without_peek() {
+    let card1 = (Rank::Ten, Suit::Diamond);
+    let card2 = (Rank::King, Suit::Club);
+    let card3 = (Rank::Six, Suit::Club);
+    let card4 = (Rank::Ace, Suit::Spade);
+    let actions = vec![Action::PlayerSurrender];
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(actions);
+    let mut deck = mock_deck(vec![card1, card2, card3, card4]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules {
+            surrender: Surrender::Late,
+            peek: false,
+            ..Rules::default()
+        },
+    )
+    .start();
+    ui.verify_dealer_blackjack_times(1);
+    ui.verify_player_surrender_times(0);
+    ui.verify_player_loose_times(1);
+    ui.verify_bankrolls(vec![990]);
+}
+
+#[test]
+fn player_ten_six_early_surrender_dealer_ace_king_blackjack() {
+    let card1 = (Rank::Ten, Suit::Diamond);
+    let card2 = (Rank::Ace, Suit::Club);
+    let card3 = (Rank::Six, Suit::Club);
+    let card4 = (Rank::King, Suit::Spade);
+    let actions = vec![Action::PlayerSurrender];
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(actions);
+    let mut deck = mock_deck(vec![card1, card2, card3, card4]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules {
+            surrender: Surrender::Early,
+            ..Rules::default()
+        },
+    )
+    .start();
+    ui.verify_offered_actions(vec![vec![
+        Action::PlayerHit,
+        Action::PlayerStay,
+        Action::PlayerDouble,
+        Action::PlayerSurrender,
+    ]]);
+    ui.verify_dealer_blackjack_times(1);
+    ui.verify_player_surrender_times(1);
+    ui.verify_player_win_times(0);
+    ui.verify_player_loose_times(0);
+    ui.verify_tie_times(0);
+}
+
+#[test]
+fn player_five_six_early_double_after_dealer_peek() {
+    let card1 = (Rank::Five, Suit::Diamond);
+    let card2 = (Rank::Ace, Suit::Club);
+    let card3 = (Rank::Six, Suit::Club);
+    let card4 = (Rank::Seven, Suit::Spade);
+    let card5 = (Rank::Nine, Suit::Heart);
+    let actions = vec![Action::PlayerDouble];
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(actions);
+    let mut deck = mock_deck(vec![card1, card2, card3, card4, card5]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules {
+            surrender: Surrender::Early,
+            ..Rules::default()
+        },
+    )
+    .start();
+    ui.verify_offered_actions(vec![vec![
+        Action::PlayerHit,
+        Action::PlayerStay,
+        Action::PlayerDouble,
+        Action::PlayerSurrender,
+    ]]);
+    ui.verify_player_hands(vec![
+        vec![card1],
+        vec![card1, card3],
+        vec![card1, card3, card5],
+    ]);
+    ui.verify_player_double_times(1);
+    ui.verify_player_surrender_times(0);
+    ui.verify_dealer_stay_times(1);
+    ui.verify_player_win_times(1);
+}
+
+#[test]
+fn player_ten_six_insured_dealer_ace_king_blackjack() {
+    let card1 = (Rank::Ten, Suit::Diamond);
+    let card2 = (Rank::Ace, Suit::Club);
+    let card3 = (Rank::Six, Suit::Club);
+    let card4 = (Rank::King, Suit::Spade);
+    let mut ui = MockInterface::new();
+    ui.set_insurance(5);
+    let mut deck = mock_deck(vec![card1, card2, card3, card4]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules::default(),
+    )
+    .start();
+    ui.verify_insurance_offers(1);
+    ui.verify_even_money_offers(0);
+    ui.verify_offered_actions(vec![]);
+    ui.verify_insurance_win_times(1);
+    ui.verify_insurance_loose_times(0);
+    ui.verify_player_loose_times(1);
+}
+
+#[test]
+fn player_ten_nine_insured_dealer_ace_seven() {
+    let card1 = (Rank::Ten, Suit::Diamond);
+    let card2 = (Rank::Ace, Suit::Club);
+    let card3 = (Rank::Nine, Suit::Club);
+    let card4 = (Rank::Seven, Suit::Spade);
+    let actions = vec![Action::PlayerStay];
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(actions);
+    ui.set_insurance(5);
+    let mut deck = mock_deck(vec![card1, card2, card3, card4]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules::default(),
+    )
+    .start();
+    ui.verify_insurance_offers(1);
+    ui.verify_insurance_win_times(0);
+    ui.verify_insurance_loose_times(1);
+    ui.verify_dealer_stay_times(1);
+    ui.verify_player_win_times(1);
+}
+
+#[test]
+fn player_ten_nine_not_offered_insurance_dealer_king_seven() {
+    let card1 = (Rank::Ten, Suit::Diamond);
+    let card2 = (Rank::King, Suit::Club);
+    let card3 = (Rank::Nine, Suit::Club);
+    let card4 = (Rank::Seven, Suit::Spade);
+    let actions = vec![Action::PlayerStay];
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(actions);
+    let mut deck = mock_deck(vec![card1, card2, card3, card4]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules::default(),
+    )
+    .start();
+    ui.verify_insurance_offers(0);
+    ui.verify_even_money_offers(0);
+    ui.verify_insurance_win_times(0);
+    ui.verify_insurance_loose_times(0);
+}
+
+#[test]
+fn player_ace_king_even_money_dealer_ace_seven() {
+    let card1 = (Rank::Ace, Suit::Diamond);
+    let card2 = (Rank::Ace, Suit::Club);
+    let card3 = (Rank::King, Suit::Club);
+    let card4 = (Rank::Seven, Suit::Spade);
+    let mut ui = MockInterface::new();
+    ui.set_take_even_money(true);
+    let mut deck = mock_deck(vec![card1, card2, card3, card4]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules::default(),
+    )
+    .start();
+    ui.verify_insurance_offers(0);
+    ui.verify_even_money_offers(1);
+    ui.verify_player_even_money_times(1);
+    ui.verify_player_win_times(0);
+    ui.verify_tie_times(0);
+}
+
+#[test]
+fn player_ace_king_declines_even_money_dealer_ace_queen() {
+    let card1 = (Rank::Ace, Suit::Diamond);
+    let card2 = (Rank::Ace, Suit::Club);
+    let card3 = (Rank::King, Suit::Club);
+    let card4 = (Rank::Queen, Suit::Spade);
+    let mut ui = MockInterface::new();
+    let mut deck = mock_deck(vec![card1, card2, card3, card4]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules::default(),
+    )
+    .start();
+    ui.verify_even_money_offers(1);
+    ui.verify_player_even_money_times(0);
+    ui.verify_player_blackjack_times(1);
+    ui.verify_dealer_blackjack_times(1);
+    ui.verify_tie_times(1);
+}
+
+#[test]
+fn player_ten_eight_dealer_ace_six_stands_on_soft_seventeen() {
+    let card1 = (Rank::Ten, Suit::Diamond);
+    let card2 = (Rank::Ace, Suit::Club);
+    let card3 = (Rank::Eight, Suit::Club);
+    let card4 = (Rank::Six, Suit::Spade);
+    let actions = vec![Action::PlayerStay];
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(actions);
+    let mut deck = mock_deck(vec![card1, card2, card3, card4]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules {
+            dealer_hits_soft_17: false,
+            ..Rules::default()
+        },
+    )
+    .start();
+    ui.verify_dealer_hands(vec![vec![card2], vec![card2, card4]]);
+    ui.verify_dealer_hit_times(0);
+    ui.verify_dealer_stay_times(1);
+    ui.verify_player_win_times(1);
+}
+
+#[test]
+fn player_eight_eight_split_cannot_double_after_split() {
+    let card1 = (Rank::Eight, Suit::Diamond);
+    let card2 = (Rank::Ten, Suit::Club);
+    let card3 = (Rank::Eight, Suit::Spade);
+    let card4 = (Rank::Seven, Suit::Spade);
+    let card5 = (Rank::Three, Suit::Heart);
+    let card6 = (Rank::Two, Suit::Heart);
+    let actions = vec![Action::PlayerSplit, Action::PlayerStay, Action::PlayerStay];
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(actions);
+    let mut deck = mock_deck(vec![card1, card2, card3, card4, card5, card6]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules {
+            double_after_split: false,
+            ..Rules::default()
+        },
+    )
+    .start();
+    ui.verify_offered_actions(vec![
+        vec![
+            Action::PlayerHit,
+            Action::PlayerStay,
+            Action::PlayerDouble,
+            Action::PlayerSplit,
+        ],
+        vec![Action::PlayerHit, Action::PlayerStay],
+        vec![Action::PlayerHit, Action::PlayerStay],
+    ]);
+    ui.verify_player_split_times(1);
+    ui.verify_player_loose_times(2);
+}
+
+#[test]
+fn player_five_four_cannot_double_on_ten_to_eleven() {
+    let card1 = (Rank::Five, Suit::Diamond);
+    let card2 = (Rank::Ten, Suit::Club);
+    let card3 = (Rank::Four, Suit::Club);
+    let card4 = (Rank::Seven, Suit::Spade);
+    let actions = vec![Action::PlayerStay];
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(actions);
+    let mut deck = mock_deck(vec![card1, card2, card3, card4]);
+    Blackjack::new(
+        &mut ui,
+        &mut deck,
+        &mut Bankroll::new(1000),
+        Rules {
+            double: Double::TenToEleven,
+            ..Rules::default()
+        },
+    )
+    .start();
+    ui.verify_offered_actions(vec![vec![Action::PlayerHit, Action::PlayerStay]]);
+    ui.verify_player_loose_times(1);
+}
+
+#[test]
+fn bankroll_player_win_pays_one_to_one() {
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(vec![Action::PlayerStay]);
+    let mut deck = mock_deck(vec![
+        (Rank::Ten, Suit::Diamond),
+        (Rank::Ten, Suit::Club),
+        (Rank::Nine, Suit::Club),
+        (Rank::Seven, Suit::Spade),
+    ]);
+    let mut bankroll = Bankroll::new(100);
+    Blackjack::new(&mut ui, &mut deck, &mut bankroll, Rules::default()).start();
+    ui.verify_bankrolls(vec![110]);
+    assert_eq!(110, bankroll.balance());
+}
+
+#[test]
+fn bankroll_natural_pays_three_to_two() {
+    let mut ui = MockInterface::new();
+    let mut deck = mock_deck(vec![
+        (Rank::Ace, Suit::Diamond),
+        (Rank::Ten, Suit::Club),
+        (Rank::King, Suit::Club),
+        (Rank::Seven, Suit::Spade),
+    ]);
+    let mut bankroll = Bankroll::new(100);
+    Blackjack::new(&mut ui, &mut deck, &mut bankroll, Rules::default()).start();
+    assert_eq!(115, bankroll.balance());
+}
+
+#[test]
+fn bankroll_natural_pays_six_to_five() {
+    let mut ui = MockInterface::new();
+    let mut deck = mock_deck(vec![
+        (Rank::Ace, Suit::Diamond),
+        (Rank::Ten, Suit::Club),
+        (Rank::King, Suit::Club),
+        (Rank::Seven, Suit::Spade),
+    ]);
+    let mut bankroll = Bankroll::new(100);
+    let rules = Rules {
+        payout: Payout::SixToFive,
+        ..Rules::default()
+    };
+    Blackjack::new(&mut ui, &mut deck, &mut bankroll, rules).start();
+    assert_eq!(112, bankroll.balance());
+}
+
+#[test]
+fn bankroll_double_win_pays_double() {
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(vec![Action::PlayerDouble]);
+    let mut deck = mock_deck(vec![
+        (Rank::Five, Suit::Diamond),
+        (Rank::Ten, Suit::Club),
+        (Rank::Six, Suit::Club),
+        (Rank::Seven, Suit::Spade),
+        (Rank::King, Suit::Heart),
+    ]);
+    let mut bankroll = Bankroll::new(100);
+    Blackjack::new(&mut ui, &mut deck, &mut bankroll, Rules::default()).start();
+    assert_eq!(120, bankroll.balance());
+}
+
+#[test]
+fn bankroll_cannot_double_without_funds() {
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(vec![Action::PlayerStay]);
+    let mut deck = mock_deck(vec![
+        (Rank::Five, Suit::Diamond),
+        (Rank::Ten, Suit::Club),
+        (Rank::Six, Suit::Club),
+        (Rank::Seven, Suit::Spade),
+    ]);
+    let mut bankroll = Bankroll::new(15);
+    Blackjack::new(&mut ui, &mut deck, &mut bankroll, Rules::default()).start();
+    ui.verify_offered_actions(vec![vec![Action::PlayerHit, Action::PlayerStay]]);
+    assert_eq!(5, bankroll.balance());
+}
+
+#[test]
+fn bankroll_split_wins_pay_each_hand() {
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(vec![
+        Action::PlayerSplit,
+        Action::PlayerStay,
+        Action::PlayerStay,
+    ]);
+    let mut deck = mock_deck(vec![
+        (Rank::Eight, Suit::Diamond),
+        (Rank::Ten, Suit::Club),
+        (Rank::Eight, Suit::Spade),
+        (Rank::Seven, Suit::Spade),
+        (Rank::King, Suit::Heart),
+        (Rank::Queen, Suit::Club),
+    ]);
+    let mut bankroll = Bankroll::new(100);
+    Blackjack::new(&mut ui, &mut deck, &mut bankroll, Rules::default()).start();
+    ui.verify_player_win_times(2);
+    assert_eq!(120, bankroll.balance());
+}
+
+#[test]
+fn bankroll_surrender_returns_half() {
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(vec![Action::PlayerSurrender]);
+    let mut deck = mock_deck(vec![
+        (Rank::Ten, Suit::Diamond),
+        (Rank::Ten, Suit::Club),
+        (Rank::Six, Suit::Club),
+        (Rank::Seven, Suit::Spade),
+    ]);
+    let mut bankroll = Bankroll::new(100);
+    let rules = Rules {
+        surrender: Surrender::Late,
+        ..Rules::default()
+    };
+    Blackjack::new(&mut ui, &mut deck, &mut bankroll, rules).start();
+    assert_eq!(95, bankroll.balance());
+}
+
+#[test]
+fn bankroll_insurance_pays_two_to_one() {
+    let mut ui = MockInterface::new();
+    ui.set_insurance(5);
+    let mut deck = mock_deck(vec![
+        (Rank::Ten, Suit::Diamond),
+        (Rank::Ace, Suit::Club),
+        (Rank::Six, Suit::Club),
+        (Rank::King, Suit::Spade),
+    ]);
+    let mut bankroll = Bankroll::new(100);
+    Blackjack::new(&mut ui, &mut deck, &mut bankroll, Rules::default()).start();
+    ui.verify_insurance_win_times(1);
+    ui.verify_player_loose_times(1);
+    assert_eq!(100, bankroll.balance());
+}
+
+#[test]
+fn bankroll_even_money_pays_one_to_one() {
+    let mut ui = MockInterface::new();
+    ui.set_take_even_money(true);
+    let mut deck = mock_deck(vec![
+        (Rank::Ace, Suit::Diamond),
+        (Rank::Ace, Suit::Club),
+        (Rank::King, Suit::Club),
+        (Rank::King, Suit::Spade),
+    ]);
+    let mut bankroll = Bankroll::new(100);
+    Blackjack::new(&mut ui, &mut deck, &mut bankroll, Rules::default()).start();
+    ui.verify_player_even_money_times(1);
+    assert_eq!(110, bankroll.balance());
+}
+
+#[test]
+fn bankroll_player_bust_loses_when_dealer_busts() {
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(vec![Action::PlayerHit]);
+    let mut deck = mock_deck(vec![
+        (Rank::Ten, Suit::Diamond),
+        (Rank::Ten, Suit::Club),
+        (Rank::Six, Suit::Club),
+        (Rank::Six, Suit::Spade),
+        (Rank::King, Suit::Heart),
+        (Rank::Queen, Suit::Heart),
+    ]);
+    let mut bankroll = Bankroll::new(100);
+    Blackjack::new(&mut ui, &mut deck, &mut bankroll, Rules::default()).start();
+    ui.verify_player_bust_times(1);
+    ui.verify_dealer_bust_times(1);
+    ui.verify_player_loose_times(1);
+    ui.verify_tie_times(0);
+    assert_eq!(90, bankroll.balance());
+}
+
+#[test]
+fn bankroll_below_table_minimum_is_not_dealt() {
+    let mut ui = MockInterface::new();
+    let mut deck = mock_deck(vec![]);
+    let mut bankroll = Bankroll::new(5);
+    Blackjack::new(&mut ui, &mut deck, &mut bankroll, Rules::default()).start();
+    ui.verify_player_hands(vec![]);
+    ui.verify_bankrolls(vec![5]);
+    assert_eq!(5, bankroll.balance());
+}
+
+#[test]
+fn play_two_rounds_with_cleared_hands() {
+    let card1 = (Rank::Ten, Suit::Diamond);
+    let card2 = (Rank::Ten, Suit::Club);
+    let card3 = (Rank::Nine, Suit::Club);
+    let card4 = (Rank::Seven, Suit::Spade);
+    let card5 = (Rank::Five, Suit::Diamond);
+    let card6 = (Rank::Ten, Suit::Heart);
+    let card7 = (Rank::Six, Suit::Club);
+    let card8 = (Rank::Nine, Suit::Spade);
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(vec![Action::PlayerStay, Action::PlayerStay]);
+    ui.set_play_again(vec![true, false]);
+    let mut deck = FakeDeck::new(
+        vec![card1, card2, card3, card4, card5, card6, card7, card8],
+        0,
+    );
+    let mut bankroll = Bankroll::new(100);
+    Blackjack::new(&mut ui, &mut deck, &mut bankroll, Rules::default()).play();
+    ui.verify_player_hands(vec![
+        vec![card1],
+        vec![card1, card3],
+        vec![card5],
+        vec![card5, card7],
+    ]);
+    ui.verify_dealer_upcards(vec![card2, card6]);
+    ui.verify_bankrolls(vec![110, 100]);
+    ui.verify_reshuffle_times(0);
+    deck.verify_reshuffle_times(0);
+    assert_eq!(100, bankroll.balance());
+}
+
+#[test]
+fn play_reshuffles_at_cut_card() {
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(vec![Action::PlayerStay]);
+    ui.set_play_again(vec![false]);
+    let mut deck = FakeDeck::new(
+        vec![
+            (Rank::Ten, Suit::Diamond),
+            (Rank::Ten, Suit::Club),
+            (Rank::Nine, Suit::Club),
+            (Rank::Seven, Suit::Spade),
+        ],
+        4,
+    );
+    let mut bankroll = Bankroll::new(100);
+    Blackjack::new(&mut ui, &mut deck, &mut bankroll, Rules::default()).play();
+    ui.verify_reshuffle_times(1);
+    deck.verify_reshuffle_times(1);
+    ui.verify_bankrolls(vec![110]);
+}
+
+#[test]
+fn play_stops_below_table_minimum() {
+    let mut ui = MockInterface::new();
+    ui.set_player_actions(vec![Action::PlayerStay]);
+    let mut deck = FakeDeck::new(
+        vec![
+            (Rank::Ten, Suit::Diamond),
+            (Rank::Ten, Suit::Club),
+            (Rank::Six, Suit::Club),
+            (Rank::Seven, Suit::Spade),
+        ],
+        0,
+    );
+    let mut bankroll = Bankroll::new(15);
+    Blackjack::new(&mut ui, &mut deck, &mut bankroll, Rules::default()).play();
+    ui.verify_bankrolls(vec![5]);
+    assert_eq!(5, bankroll.balance());
+}
@@ -1,4 +1,4 @@
-use blackjack::backend::card::{Card, Rank, Suit};
+use blackjack::backend::card::{Card, ParseCardError, Rank, Suit};
 use blackjack::behaviour::card_like::CardLike;
 
 const RANKS: [Rank; 13] = [
@@ -54,3 +54,60 @@ fn to_string_case(suit: Suit, rank: Rank) {
     let actual_string = card.to_string();
     assert_eq!(expected_string, actual_string)
 }
+
+#[test]
+fn parse_rank() {
+    let notations = [
+        "A", "2", "3", "4", "5", "6", "7", "8", "9", "10", "J", "Q", "K",
+    ];
+    RANKS
+        .iter()
+        .zip(notations)
+        .for_each(|(r, n)| assert_eq!(Ok(*r), n.parse()));
+    assert_eq!(Ok(Rank::Ten), "T".parse());
+    assert_eq!(Ok(Rank::King), "k".parse());
+    assert_eq!(
+        Err(ParseCardError::InvalidRank("11".to_string())),
+        "11".parse::<Rank>()
+    );
+}
+
+#[test]
+fn parse_suit() {
+    SUITS
+        .iter()
+        .zip(["S", "H", "C", "D"])
+        .for_each(|(s, n)| assert_eq!(Ok(*s), n.parse()));
+    SUITS
+        .iter()
+        .zip(["♠", "♥", "♣", "♦"])
+        .for_each(|(s, n)| assert_eq!(Ok(*s), n.parse()));
+    assert_eq!(Ok(Suit::Heart), "h".parse());
+    assert_eq!(Ok(Suit::Heart), "♥\u{FE0F}".parse());
+    assert_eq!(
+        Err(ParseCardError::InvalidSuit("X".to_string())),
+        "X".parse::<Suit>()
+    );
+}
+
+#[test]
+fn parse_card() {
+    assert_eq!(Ok(Card::new(Suit::Spade, Rank::Ace)), "As".parse());
+    assert_eq!(Ok(Card::new(Suit::Spade, Rank::Ace)), "AS".parse());
+    assert_eq!(Ok(Card::new(Suit::Heart, Rank::Ten)), "10h".parse());
+    assert_eq!(Ok(Card::new(Suit::Heart, Rank::Ten)), "Th".parse());
+    assert_eq!(Ok(Card::new(Suit::Diamond, Rank::Queen)), "Q♦".parse());
+    assert_eq!(Ok(Card::new(Suit::Heart, Rank::Ace)), "A♥\u{FE0F}".parse());
+    assert_eq!(
+        Err(ParseCardError::InvalidSuit("Z".to_string())),
+        "KZ".parse::<Card>()
+    );
+    assert_eq!(
+        Err(ParseCardError::InvalidRank("1".to_string())),
+        "1S".parse::<Card>()
+    );
+    assert_eq!(
+        Err(ParseCardError::InvalidSuit("".to_string())),
+        "".parse::<Card>()
+    );
+}
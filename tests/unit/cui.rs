@@ -0,0 +1,47 @@
+use blackjack::backend::bankroll::Bankroll;
+use blackjack::backend::blackjack::Blackjack;
+use blackjack::backend::card::Card;
+use blackjack::backend::deck::Deck;
+use blackjack::backend::hand::Hand;
+use blackjack::backend::rules::Rules;
+use blackjack::cui::Cui;
+
+fn play(input: &str, deck: &str, rich: bool) -> String {
+    let mut cui = Cui::with_io(input.as_bytes(), Vec::new());
+    if rich {
+        cui = cui.with_rich_mode();
+    }
+    let mut deck: Deck = deck.parse().unwrap();
+    let mut bankroll = Bankroll::new(1000);
+    Blackjack::<_, _, _, Hand<Card>>::new(&mut cui, &mut deck, &mut bankroll, Rules::default())
+        .play();
+    String::from_utf8(cui.output().clone()).unwrap()
+}
+
+#[test]
+fn plain_round() {
+    let output = play("10\ns\nn\n", "5H 10S 6D 9C", false);
+    assert!(output.starts_with("Place your wager, from 10 to 500.\n"));
+    assert!(output.contains("Your cards: Five of Hearts, Six of Diamonds\n"));
+    assert!(output.contains("Dealers hand: Ten of Spades, ??\n"));
+    assert!(output.contains("The dealer wins!\nYour balance is 990.\n"));
+}
+
+#[test]
+fn rich_mode_clears_the_table_between_rounds() {
+    let output = play("10\ns\ny\n10\ns\nn\n", "5H 10S 6D 9C 4H 8S 3D 9D", true);
+    let (first, second) = output.split_once("Your balance is 990.").unwrap();
+    assert!(first.contains("Dealer: 19"));
+    assert!(!second.contains("Dealer: 19"));
+    let table = second.lines().find(|line| line.contains("You: 4")).unwrap();
+    assert_eq!("    You: 4", table);
+    assert!(second.contains("Dealer: 17"));
+}
+
+#[test]
+fn closed_input_stands_and_stops() {
+    let output = play("20\n", "5H 10S 6D 9C", false);
+    assert!(output.contains("Would you like to (h)it, (s)tay or (d)ouble?\n"));
+    assert!(output.contains("The dealer wins!\nYour balance is 980.\n"));
+    assert!(output.ends_with("Would you like to play again? (y)es or (n)o?\n"));
+}
@@ -0,0 +1,88 @@
+use blackjack::backend::card::{Card, Rank, Suit};
+use blackjack::backend::dealer::{Composition, Outcomes};
+use blackjack::backend::rules::Rules;
+
+fn stands_on_soft_17() -> Rules {
+    Rules {
+        dealer_hits_soft_17: false,
+        ..Rules::default()
+    }
+}
+
+fn total(outcomes: &Outcomes) -> f64 {
+    (17..=21).map(|p| outcomes.points(p)).sum::<f64>() + outcomes.natural() + outcomes.bust()
+}
+
+#[test]
+fn counts_cards() {
+    let mut shoe = Composition::with_decks(2);
+    assert_eq!(104, shoe.len());
+    assert_eq!(8, shoe.count(Rank::Ace));
+    assert_eq!(32, shoe.count(Rank::Jack));
+    assert!(shoe.remove(Rank::King));
+    assert_eq!(31, shoe.count(Rank::Ten));
+    let cards = [
+        Card::new(Suit::Heart, Rank::Ace),
+        Card::new(Suit::Spade, Rank::Queen),
+    ];
+    let mut shoe = Composition::from_cards(&cards);
+    assert_eq!(0.5, shoe.chance(Rank::Ten));
+    assert!(shoe.remove(Rank::Ace));
+    assert!(!shoe.remove(Rank::Ace));
+    assert!(shoe.remove(Rank::Ten));
+    assert!(shoe.is_empty());
+}
+
+#[test]
+fn exact_outcomes_of_a_small_shoe() {
+    let cards = [
+        Card::new(Suit::Heart, Rank::Ace),
+        Card::new(Suit::Spade, Rank::Ten),
+        Card::new(Suit::Club, Rank::Jack),
+        Card::new(Suit::Diamond, Rank::King),
+    ];
+    let outcomes = Outcomes::new(
+        &Rules::default(),
+        &Composition::from_cards(&cards),
+        Rank::Ten,
+    );
+    assert_eq!(0.25, outcomes.natural());
+    assert_eq!(0.75, outcomes.points(20));
+    assert_eq!(0.0, outcomes.bust());
+    let outcomes = outcomes.without_natural();
+    assert_eq!(0.0, outcomes.natural());
+    assert_eq!(1.0, outcomes.points(20));
+}
+
+#[test]
+fn hits_soft_17_by_the_rules() {
+    let cards = [
+        Card::new(Suit::Heart, Rank::Six),
+        Card::new(Suit::Spade, Rank::Two),
+    ];
+    let shoe = Composition::from_cards(&cards);
+    let outcomes = Outcomes::new(&stands_on_soft_17(), &shoe, Rank::Ace);
+    assert_eq!(0.5, outcomes.points(17));
+    assert_eq!(0.5, outcomes.points(19));
+    let outcomes = Outcomes::new(&Rules::default(), &shoe, Rank::Ace);
+    assert_eq!(0.0, outcomes.points(17));
+    assert_eq!(1.0, outcomes.points(19));
+}
+
+#[test]
+fn outcomes_of_a_full_shoe() {
+    let mut shoe = Composition::with_decks(6);
+    shoe.remove(Rank::Six);
+    let outcomes = Outcomes::new(&stands_on_soft_17(), &shoe, Rank::Six);
+    assert!((total(&outcomes) - 1.0).abs() < 1e-9);
+    assert!((0.41..0.43).contains(&outcomes.bust()));
+    assert_eq!(0.0, outcomes.natural());
+
+    let mut shoe = Composition::with_decks(6);
+    shoe.remove(Rank::Ace);
+    let outcomes = Outcomes::new(&Rules::default(), &shoe, Rank::Ace);
+    assert!((total(&outcomes) - 1.0).abs() < 1e-9);
+    assert!((outcomes.natural() - 96.0 / 311.0).abs() < 1e-12);
+    let outcomes = outcomes.without_natural();
+    assert!((total(&outcomes) - 1.0).abs() < 1e-9);
+}
@@ -5,6 +5,7 @@ use blackjack::behaviour::card_iter::CardIter;
 use blackjack::behaviour::card_like::{CardLike, MockCardLike};
 use blackjack::behaviour::draw::{CannotDrawFromEmpty, DrawTo};
 use blackjack::behaviour::score::{Score, Value};
+use blackjack::behaviour::split::Split;
 use std::collections::HashSet;
 
 fn assert_one_new_card_in_hand(
@@ -150,7 +151,19 @@ fn score_king_six_five() {
             (Rank::Six, Suit::Spade),
             (Rank::Five, Suit::Diamond),
         ],
-        Value::Blackjack,
+        Value::Points(21, false),
+    );
+}
+
+#[test]
+fn score_ace_five_five() {
+    score_case(
+        vec![
+            (Rank::Ace, Suit::Club),
+            (Rank::Five, Suit::Spade),
+            (Rank::Five, Suit::Diamond),
+        ],
+        Value::Points(21, true),
     );
 }
 
@@ -177,3 +190,43 @@ fn score_king_eight_ace() {
         Value::Points(19, false),
     );
 }
+
+#[test]
+fn split_pair() {
+    let mut deck = mock_deck(vec![(Rank::Eight, Suit::Club), (Rank::Eight, Suit::Heart)]);
+    let mut hand = Hand::new();
+    while hand.draw_from(&mut deck).is_ok() {}
+
+    let split_hand = hand.split();
+
+    let cards: Vec<(Rank, Suit)> = hand.iter().map(|c| (c.get_rank(), c.get_suit())).collect();
+    let split_cards: Vec<(Rank, Suit)> = split_hand
+        .iter()
+        .map(|c| (c.get_rank(), c.get_suit()))
+        .collect();
+    assert_eq!(vec![(Rank::Eight, Suit::Club)], cards);
+    assert_eq!(vec![(Rank::Eight, Suit::Heart)], split_cards);
+}
+
+#[test]
+fn split_ace_and_ten_is_not_a_natural() {
+    let mut deck = mock_deck(vec![(Rank::Ace, Suit::Club), (Rank::Ace, Suit::Heart)]);
+    let mut hand = Hand::new();
+    while hand.draw_from(&mut deck).is_ok() {}
+
+    let mut split_hand = hand.split();
+    let mut deck = mock_deck(vec![(Rank::King, Suit::Club), (Rank::Ten, Suit::Spade)]);
+    hand.draw_from(&mut deck).unwrap();
+    split_hand.draw_from(&mut deck).unwrap();
+
+    assert_eq!(Value::Points(21, true), hand.score());
+    assert_eq!(Value::Points(21, true), split_hand.score());
+}
+
+#[test]
+fn display_score() {
+    assert_eq!(Value::Bust.to_string(), "bust");
+    assert_eq!(Value::Blackjack.to_string(), "blackjack");
+    assert_eq!(Value::Points(17, true).to_string(), "soft 17");
+    assert_eq!(Value::Points(17, false).to_string(), "17");
+}
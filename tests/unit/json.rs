@@ -0,0 +1,137 @@
+use blackjack::backend::bankroll::Bankroll;
+use blackjack::backend::blackjack::Blackjack;
+use blackjack::backend::card::Card;
+use blackjack::backend::deck::Deck;
+use blackjack::backend::hand::Hand;
+use blackjack::backend::rules::Rules;
+use blackjack::behaviour::draw::DrawTo;
+use blackjack::behaviour::interface::{Action, Event, Interface};
+use blackjack::behaviour::split::Split;
+use blackjack::json::JsonLines;
+use std::io::Cursor;
+
+type Json = JsonLines<Cursor<String>, Vec<u8>>;
+
+fn json(input: &str) -> Json {
+    JsonLines::new(Cursor::new(input.to_string()), Vec::new())
+}
+
+fn output(json: &Json) -> Vec<String> {
+    String::from_utf8(json.output().clone())
+        .unwrap()
+        .lines()
+        .map(String::from)
+        .collect()
+}
+
+#[test]
+fn play_round() {
+    let mut json = json("{\"wager\": 20}\n{\"action\": \"hit\"}\n{ \"action\" : \"stay\" }\n");
+    let mut deck: Deck = "5H 10S 6D 6C 9S 10D".parse().unwrap();
+    let mut bankroll = Bankroll::new(1000);
+    Blackjack::<_, _, _, Hand<Card>>::new(&mut json, &mut deck, &mut bankroll, Rules::default())
+        .start();
+    assert_eq!(
+        vec![
+            r#"{"request":"wager","min":10,"max":500}"#,
+            r#"{"event":"player_hand","value":["5H"],"score":{"points":[5,false]}}"#,
+            r#"{"event":"dealer_hand","value":["TS"],"score":{"points":[10,false]}}"#,
+            r#"{"event":"player_hand","value":["5H","6D"],"score":{"points":[11,false]}}"#,
+            r#"{"event":"dealer_upcard","value":"TS"}"#,
+            r#"{"request":"action","actions":["hit","stay","double"]}"#,
+            r#"{"event":"player_hand","value":["5H","6D","9S"],"score":{"points":[20,false]}}"#,
+            r#"{"request":"action","actions":["hit","stay"]}"#,
+            r#"{"event":"dealer_reveal","value":["TS","6C"],"score":{"points":[16,false]}}"#,
+            r#"{"event":"dealer_hit"}"#,
+            r#"{"event":"dealer_hand","value":["TS","6C","TD"],"score":"bust"}"#,
+            r#"{"event":"dealer_bust"}"#,
+            r#"{"event":"player_win"}"#,
+            r#"{"event":"bankroll","value":1020}"#,
+        ],
+        output(&json)
+    );
+}
+
+#[test]
+fn reject_invalid_answers() {
+    let mut json = json(
+        "hit\n{\"action\": \"split\"}\n{\"wager\": 10}\n{\"action\": \"stay\", \"note\": null}\n",
+    );
+    let action =
+        Interface::<Hand<Card>>::get_action(&mut json, &[Action::PlayerHit, Action::PlayerStay]);
+    assert_eq!(Action::PlayerStay, action);
+    let error = r#"{"error":"expected a valid \"action\""}"#;
+    assert_eq!(vec![error; 3], output(&json)[1..]);
+}
+
+#[test]
+fn read_amounts() {
+    let mut json = json("{\"wager\": 5}\n{\"wager\": 12.5}\n{\"wager\": 25}\n{\"insurance\": 0}\n");
+    assert_eq!(25, Interface::<Hand<Card>>::get_wager(&mut json, 10, 500));
+    assert_eq!(0, Interface::<Hand<Card>>::get_insurance(&mut json, 5));
+    assert_eq!(
+        r#"{"request":"insurance","max":5}"#,
+        output(&json).last().unwrap()
+    );
+}
+
+#[test]
+fn read_booleans() {
+    let mut json =
+        json("{\"even_money\": \"yes\"}\n{\"even_money\": true}\n{\"play_again\": false}\n");
+    assert!(Interface::<Hand<Card>>::get_even_money(&mut json));
+    assert!(!Interface::<Hand<Card>>::play_again(&mut json));
+}
+
+#[test]
+fn send_events() {
+    let mut json = json("");
+    Interface::<Hand<Card>>::send(&mut json, Event::CurrentHand(1));
+    Interface::<Hand<Card>>::send(&mut json, Event::PlayerLoose);
+    assert_eq!(
+        vec![
+            r#"{"event":"current_hand","value":1}"#,
+            r#"{"event":"player_loose"}"#
+        ],
+        output(&json)
+    );
+}
+
+#[test]
+fn read_any_json() {
+    let mut json = json(concat!(
+        "{\"wager\": +20}\n",
+        "{\"wager\": NaN}\n",
+        "{\"wager\": 20, \"note\": {\"says\": [\"\\b\\f\", \"\\ud83c\\udca1\"]}}\n",
+    ));
+    assert_eq!(20, Interface::<Hand<Card>>::get_wager(&mut json, 10, 500));
+    let error = r#"{"error":"expected a valid \"wager\""}"#;
+    assert_eq!(vec![error; 2], output(&json)[1..]);
+}
+
+#[test]
+fn stop_when_input_closes() {
+    let mut json = json("{\"wager\": 20}\n");
+    let mut deck: Deck = "5H 10S 6D 6C 9S 10D".parse().unwrap();
+    let mut bankroll = Bankroll::new(1000);
+    Blackjack::<_, _, _, Hand<Card>>::new(&mut json, &mut deck, &mut bankroll, Rules::default())
+        .play();
+    assert_eq!(r#"{"request":"play_again"}"#, output(&json).last().unwrap());
+    assert_eq!(1020, bankroll.balance());
+}
+
+#[test]
+fn send_split_hand_score() {
+    let mut json = json("");
+    let mut hand: Hand<Card> = Hand::new();
+    let mut deck: Deck = "AS AH KD".parse().unwrap();
+    hand.draw_from(&mut deck).unwrap();
+    hand.draw_from(&mut deck).unwrap();
+    hand.split();
+    hand.draw_from(&mut deck).unwrap();
+    Interface::<Hand<Card>>::send(&mut json, Event::PlayerHand(&hand));
+    assert_eq!(
+        vec![r#"{"event":"player_hand","value":["AS","KD"],"score":{"points":[21,true]}}"#],
+        output(&json)
+    );
+}
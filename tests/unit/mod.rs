@@ -1,3 +1,14 @@
+mod bankroll;
 mod blackjack;
 mod card;
+mod cui;
+mod dealer;
 mod hand;
+#[cfg(feature = "serde")]
+mod json;
+mod rules;
+#[cfg(feature = "serde")]
+mod serialize;
+mod strategy;
+mod style;
+mod tui;
@@ -0,0 +1,29 @@
+use blackjack::backend::rules::{Payout, Rules};
+
+#[test]
+fn dealer_hits_soft_seventeen() {
+    let rules = Rules::default();
+    assert!(rules.is_dealer_hitting(16, false));
+    assert!(rules.is_dealer_hitting(17, true));
+    assert!(!rules.is_dealer_hitting(17, false));
+    assert!(!rules.is_dealer_hitting(18, true));
+}
+
+#[test]
+fn dealer_stands_on_soft_seventeen() {
+    let rules = Rules {
+        dealer_hits_soft_17: false,
+        ..Rules::default()
+    };
+    assert!(rules.is_dealer_hitting(16, true));
+    assert!(!rules.is_dealer_hitting(17, true));
+    assert!(!rules.is_dealer_hitting(17, false));
+}
+
+#[test]
+fn payout_rounds_down() {
+    assert_eq!(15, Payout::ThreeToTwo.pay(10));
+    assert_eq!(7, Payout::ThreeToTwo.pay(5));
+    assert_eq!(12, Payout::SixToFive.pay(10));
+    assert_eq!(10, Payout::OneToOne.pay(10));
+}
@@ -0,0 +1,113 @@
+use blackjack::backend::card::{Card, Rank, Suit};
+use blackjack::backend::deck::Deck;
+use blackjack::backend::hand::Hand;
+use blackjack::behaviour::draw::DrawTo;
+use blackjack::behaviour::interface::{Action, Event, OwnedEvent};
+use blackjack::behaviour::score::Value;
+
+fn hand(cards: &str) -> Hand<Card> {
+    let mut deck: Deck = cards.parse().unwrap();
+    let mut hand = Hand::new();
+    while hand.draw_from(&mut deck).is_ok() {}
+    hand
+}
+
+#[test]
+fn serialize_card() {
+    let card = Card::new(Suit::Heart, Rank::Ten);
+    let json = serde_json::to_string(&card).unwrap();
+    assert_eq!(r#""TH""#, json);
+    assert_eq!(card, serde_json::from_str(&json).unwrap());
+}
+
+#[test]
+fn serialize_value() {
+    let values = [Value::Bust, Value::Blackjack, Value::Points(17, true)];
+    values.iter().for_each(|value| {
+        let json = serde_json::to_string(value).unwrap();
+        assert_eq!(*value, serde_json::from_str(&json).unwrap());
+    });
+    assert_eq!(
+        r#"{"points":[17,true]}"#,
+        serde_json::to_string(&Value::Points(17, true)).unwrap()
+    );
+}
+
+#[test]
+fn serialize_action() {
+    let json = serde_json::to_string(&Action::PlayerDouble).unwrap();
+    assert_eq!(r#""double""#, json);
+    assert_eq!(Action::PlayerDouble, serde_json::from_str(&json).unwrap());
+}
+
+#[test]
+fn serialize_event() {
+    let hand = hand("AS 10H");
+    let card = Card::new(Suit::Club, Rank::Seven);
+    let cards = || {
+        vec![
+            Card::new(Suit::Spade, Rank::Ace),
+            Card::new(Suit::Heart, Rank::Ten),
+        ]
+    };
+    let events: [(Event<Hand<Card>>, OwnedEvent<Card>); 22] = [
+        (Event::PlayerBust, OwnedEvent::PlayerBust),
+        (Event::PlayerBlackjack, OwnedEvent::PlayerBlackjack),
+        (Event::PlayerDouble, OwnedEvent::PlayerDouble),
+        (Event::PlayerSplit, OwnedEvent::PlayerSplit),
+        (Event::CurrentHand(1), OwnedEvent::CurrentHand(1)),
+        (Event::DealerBust, OwnedEvent::DealerBust),
+        (Event::DealerBlackjack, OwnedEvent::DealerBlackjack),
+        (Event::DealerStay, OwnedEvent::DealerStay),
+        (Event::DealerHit, OwnedEvent::DealerHit),
+        (Event::PlayerWin, OwnedEvent::PlayerWin),
+        (Event::PlayerLoose, OwnedEvent::PlayerLoose),
+        (Event::Tie, OwnedEvent::Tie),
+        (Event::PlayerSurrender, OwnedEvent::PlayerSurrender),
+        (Event::PlayerEvenMoney, OwnedEvent::PlayerEvenMoney),
+        (Event::InsuranceWin, OwnedEvent::InsuranceWin),
+        (Event::InsuranceLoose, OwnedEvent::InsuranceLoose),
+        (Event::Bankroll(990), OwnedEvent::Bankroll(990)),
+        (Event::Reshuffle, OwnedEvent::Reshuffle),
+        (Event::PlayerHand(&hand), OwnedEvent::PlayerHand(cards())),
+        (Event::DealerHand(&hand), OwnedEvent::DealerHand(cards())),
+        (
+            Event::DealerUpcard(&card),
+            OwnedEvent::DealerUpcard(Card::new(Suit::Club, Rank::Seven)),
+        ),
+        (
+            Event::DealerReveal(&hand),
+            OwnedEvent::DealerReveal(cards()),
+        ),
+    ];
+    for (event, owned) in events {
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(json, serde_json::to_string(&owned).unwrap());
+        assert_eq!(owned, serde_json::from_str(&json).unwrap());
+    }
+}
+
+#[test]
+fn serialize_event_hand() {
+    let hand = hand("AS 10H");
+    let json = serde_json::to_string(&Event::PlayerHand(&hand)).unwrap();
+    assert_eq!(r#"{"event":"player_hand","value":["AS","TH"]}"#, json);
+    assert_eq!(
+        OwnedEvent::PlayerHand(vec![
+            Card::new(Suit::Spade, Rank::Ace),
+            Card::new(Suit::Heart, Rank::Ten),
+        ]),
+        serde_json::from_str(&json).unwrap()
+    );
+}
+
+#[test]
+fn serialize_event_upcard() {
+    let card = Card::new(Suit::Club, Rank::Seven);
+    let json = serde_json::to_string(&Event::<Hand<Card>>::DealerUpcard(&card)).unwrap();
+    assert_eq!(r#"{"event":"dealer_upcard","value":"7C"}"#, json);
+    assert_eq!(
+        OwnedEvent::DealerUpcard(card),
+        serde_json::from_str(&json).unwrap()
+    );
+}
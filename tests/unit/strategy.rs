@@ -0,0 +1,229 @@
+use blackjack::backend::card::Rank;
+use blackjack::backend::rules::{Rules, Surrender};
+use blackjack::backend::strategy::{ParseStrategyError, Play, Strategy};
+use blackjack::behaviour::interface::Action;
+
+fn six_decks_s17() -> Strategy {
+    Strategy::new(Rules {
+        dealer_hits_soft_17: false,
+        decks: 6,
+        surrender: Surrender::Late,
+        ..Rules::default()
+    })
+}
+
+#[test]
+fn hard_totals() {
+    let strategy = six_decks_s17();
+    assert_eq!(Play::Hit, strategy.play(&[Rank::Two, Rank::Six], Rank::Six));
+    assert_eq!(
+        Play::Stand,
+        strategy.play(&[Rank::Ten, Rank::Two], Rank::Four)
+    );
+    assert_eq!(Play::Hit, strategy.play(&[Rank::Ten, Rank::Two], Rank::Two));
+    assert_eq!(
+        Play::DoubleOrHit,
+        strategy.play(&[Rank::Six, Rank::Five], Rank::Ten)
+    );
+    assert_eq!(
+        Play::Hit,
+        strategy.play(&[Rank::Six, Rank::Five], Rank::Ace)
+    );
+    assert_eq!(
+        Play::SurrenderOrHit,
+        strategy.play(&[Rank::Ten, Rank::Six], Rank::King)
+    );
+    assert_eq!(
+        Play::Stand,
+        strategy.play(&[Rank::Ten, Rank::Seven], Rank::Ace)
+    );
+}
+
+#[test]
+fn soft_totals() {
+    let strategy = six_decks_s17();
+    assert_eq!(
+        Play::DoubleOrStand,
+        strategy.play(&[Rank::Ace, Rank::Seven], Rank::Three)
+    );
+    assert_eq!(
+        Play::Stand,
+        strategy.play(&[Rank::Ace, Rank::Seven], Rank::Seven)
+    );
+    assert_eq!(
+        Play::Hit,
+        strategy.play(&[Rank::Ace, Rank::Seven], Rank::Nine)
+    );
+    assert_eq!(
+        Play::Stand,
+        strategy.play(&[Rank::Ace, Rank::Eight], Rank::Six)
+    );
+}
+
+#[test]
+fn pairs() {
+    let strategy = six_decks_s17();
+    assert_eq!(
+        Play::Split,
+        strategy.play(&[Rank::Ace, Rank::Ace], Rank::Ten)
+    );
+    assert_eq!(
+        Play::Split,
+        strategy.play(&[Rank::Eight, Rank::Eight], Rank::Ten)
+    );
+    assert_eq!(
+        Play::Stand,
+        strategy.play(&[Rank::Ten, Rank::Ten], Rank::Six)
+    );
+    assert_eq!(
+        Play::Stand,
+        strategy.play(&[Rank::Nine, Rank::Nine], Rank::Seven)
+    );
+    assert_eq!(
+        Play::DoubleOrHit,
+        strategy.play(&[Rank::Five, Rank::Five], Rank::Nine)
+    );
+}
+
+#[test]
+fn single_deck() {
+    let strategy = Strategy::new(Rules::default());
+    let cells = [
+        (Play::DoubleOrHit, [Rank::Five, Rank::Three], Rank::Five),
+        (Play::DoubleOrHit, [Rank::Six, Rank::Two], Rank::Six),
+        (Play::Hit, [Rank::Five, Rank::Three], Rank::Four),
+        (Play::DoubleOrHit, [Rank::Seven, Rank::Two], Rank::Two),
+        (Play::Split, [Rank::Four, Rank::Four], Rank::Four),
+        (Play::Split, [Rank::Three, Rank::Three], Rank::Eight),
+        (Play::Stand, [Rank::Seven, Rank::Seven], Rank::Ten),
+        (Play::DoubleOrHit, [Rank::Ace, Rank::Two], Rank::Four),
+        (Play::DoubleOrHit, [Rank::Ace, Rank::Six], Rank::Two),
+        (Play::DoubleOrStand, [Rank::Ace, Rank::Eight], Rank::Six),
+        (Play::Stand, [Rank::Ace, Rank::Eight], Rank::Five),
+    ];
+    for (play, cards, upcard) in cells {
+        assert_eq!(
+            play,
+            strategy.play(&cards, upcard),
+            "{:?} vs {:?}",
+            cards,
+            upcard
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "a strategy needs at least one deck")]
+fn reject_no_decks() {
+    Strategy::new(Rules {
+        decks: 0,
+        ..Rules::default()
+    });
+}
+
+#[test]
+fn dealer_hitting_soft_seventeen_changes_plays() {
+    let strategy = Strategy::new(Rules {
+        dealer_hits_soft_17: true,
+        decks: 1,
+        surrender: Surrender::Late,
+        ..Rules::default()
+    });
+    assert_eq!(
+        Play::DoubleOrHit,
+        strategy.play(&[Rank::Six, Rank::Five], Rank::Ace)
+    );
+    assert_eq!(
+        Play::SurrenderOrStand,
+        strategy.play(&[Rank::Ten, Rank::Seven], Rank::Ace)
+    );
+}
+
+#[test]
+fn advise_falls_back_when_not_offered() {
+    let strategy = six_decks_s17();
+    let hit_or_stay = [Action::PlayerHit, Action::PlayerStay];
+    assert_eq!(
+        Action::PlayerHit,
+        strategy.advise(
+            &[Rank::Six, Rank::Three, Rank::Two],
+            Rank::Ten,
+            &hit_or_stay
+        )
+    );
+    assert_eq!(
+        Action::PlayerHit,
+        strategy.advise(&[Rank::Ten, Rank::Six], Rank::Ten, &hit_or_stay)
+    );
+    assert_eq!(
+        Action::PlayerStay,
+        strategy.advise(&[Rank::Eight, Rank::Eight], Rank::Six, &hit_or_stay)
+    );
+    assert_eq!(
+        Action::PlayerSplit,
+        strategy.advise(
+            &[Rank::Eight, Rank::Eight],
+            Rank::Six,
+            &[Action::PlayerHit, Action::PlayerStay, Action::PlayerSplit]
+        )
+    );
+}
+
+#[test]
+fn write_then_read_chart() {
+    let strategy = six_decks_s17();
+    let chart = strategy.to_string();
+    assert!(chart.starts_with("hard,2,3,4,5,6,7,8,9,10,A\n4,H,H,H,H,H,H,H,H,H,H\n"));
+    assert!(chart.contains("\n16,S,S,S,S,S,H,H,Rh,Rh,Rh\n"));
+    assert!(chart.contains("\npairs,2,3,4,5,6,7,8,9,10,A\n"));
+    assert_eq!(Ok(strategy), chart.parse());
+}
+
+#[test]
+fn read_chart_with_comments_and_whitespace() {
+    let chart = six_decks_s17()
+        .to_string()
+        .replace(',', "  ")
+        .replacen("hard", "# Six decks, dealer stands on soft 17\n\nHARD", 1)
+        .replacen("\n16  S", "\n  16  s", 1)
+        .replacen("Rh\n17", "rh  # surrender when allowed\n17", 1);
+    assert_eq!(Ok(six_decks_s17()), chart.parse());
+}
+
+#[test]
+fn read_chart_errors() {
+    let chart = six_decks_s17().to_string();
+    let parse = |chart: &str| chart.parse::<Strategy>().unwrap_err();
+    assert_eq!(
+        ParseStrategyError::InvalidHeader(1),
+        parse(&chart.replacen("9,10,A", "9,A,10", 1))
+    );
+    assert_eq!(
+        ParseStrategyError::InvalidRow(1, "4".to_string()),
+        parse("4,H,H,H,H,H,H,H,H,H,H")
+    );
+    assert_eq!(
+        ParseStrategyError::InvalidRow(3, "22".to_string()),
+        parse(&chart.replacen("\n5,", "\n22,", 1))
+    );
+    assert_eq!(
+        ParseStrategyError::InvalidRow(3, "4".to_string()),
+        parse(&chart.replacen("\n5,", "\n4,", 1))
+    );
+    assert_eq!(
+        ParseStrategyError::WrongLength(2),
+        parse(&chart.replacen("\n4,H,", "\n4,", 1))
+    );
+    assert_eq!(
+        ParseStrategyError::InvalidCell(2, "X".to_string()),
+        parse(&chart.replacen("\n4,H,", "\n4,X,", 1))
+    );
+    assert_eq!(
+        ParseStrategyError::InvalidCell(2, "P".to_string()),
+        parse(&chart.replacen("\n4,H,", "\n4,P,", 1))
+    );
+    assert_eq!(
+        ParseStrategyError::MissingRow("hard".to_string(), "13".to_string()),
+        parse(&chart.replacen("\n13,", "\n# 13,", 1))
+    );
+}
@@ -0,0 +1,80 @@
+use blackjack::backend::card::{Card, Rank, Suit};
+use blackjack::backend::deck::Deck;
+use blackjack::backend::hand::Hand;
+use blackjack::backend::style::{Style, Styled};
+use blackjack::behaviour::card_iter::CardIter;
+use blackjack::behaviour::draw::DrawTo;
+
+fn cards() -> Vec<Card> {
+    vec![
+        Card::new(Suit::Heart, Rank::Ten),
+        Card::new(Suit::Spade, Rank::Ace),
+    ]
+}
+
+#[test]
+fn style_card() {
+    let card = Card::new(Suit::Heart, Rank::Ten);
+    assert_eq!("Ten of Hearts", Styled(&card, Style::Long).to_string());
+    assert_eq!("TH", Styled(&card, Style::Short).to_string());
+    assert_eq!("10♥", Styled(&card, Style::Symbol).to_string());
+    assert_eq!("\u{1F0BA}", Styled(&card, Style::Glyph).to_string());
+    assert_eq!(
+        "+-----+\n|10   |\n|  ♥  |\n|   10|\n+-----+",
+        Styled(&card, Style::Art).to_string()
+    );
+}
+
+#[test]
+fn style_glyphs() {
+    let glyphs = [
+        (Card::new(Suit::Spade, Rank::Ace), '\u{1F0A1}'),
+        (Card::new(Suit::Diamond, Rank::Five), '\u{1F0C5}'),
+        (Card::new(Suit::Club, Rank::Jack), '\u{1F0DB}'),
+        (Card::new(Suit::Heart, Rank::Queen), '\u{1F0BD}'),
+        (Card::new(Suit::Spade, Rank::King), '\u{1F0AE}'),
+    ];
+    glyphs.iter().for_each(|(card, glyph)| {
+        assert_eq!(glyph.to_string(), Styled(card, Style::Glyph).to_string())
+    });
+}
+
+#[test]
+fn style_cards() {
+    let cards = cards();
+    assert_eq!(
+        "Ten of Hearts, Ace of Spades",
+        Styled(cards.as_slice(), Style::Long).to_string()
+    );
+    assert_eq!("TH AS", Styled(cards.as_slice(), Style::Short).to_string());
+    assert_eq!(
+        "10♥ A♠",
+        Styled(cards.as_slice(), Style::Symbol).to_string()
+    );
+    assert_eq!(
+        "+-----+ +-----+\n|10   | |A    |\n|  ♥  | |  ♠  |\n|   10| |    A|\n+-----+ +-----+",
+        Styled(cards.as_slice(), Style::Art).to_string()
+    );
+}
+
+#[test]
+fn style_hand() {
+    let mut hand = Hand::new();
+    let mut deck = Deck::from_cards(cards());
+    while hand.draw_from(&mut deck).is_ok() {}
+    assert_eq!(
+        "TH AS",
+        Styled(hand.iter().as_slice(), Style::Short).to_string()
+    );
+    assert_eq!(
+        hand.to_string(),
+        Styled(hand.iter().as_slice(), Style::Long).to_string()
+    );
+}
+
+#[test]
+fn style_no_cards() {
+    let cards: Vec<Card> = vec![];
+    assert_eq!("", Styled(cards.as_slice(), Style::Short).to_string());
+    assert_eq!("", Styled(cards.as_slice(), Style::Art).to_string());
+}
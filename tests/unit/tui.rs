@@ -0,0 +1,104 @@
+use blackjack::backend::bankroll::Bankroll;
+use blackjack::backend::blackjack::Blackjack;
+use blackjack::backend::card::Card;
+use blackjack::backend::deck::Deck;
+use blackjack::backend::hand::Hand;
+use blackjack::backend::rules::Rules;
+use blackjack::behaviour::interface::{Action, Event, Interface};
+use blackjack::tui::{Tui, BACKSPACE, ENTER, ESCAPE};
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use std::vec::IntoIter;
+
+fn tui(keys: &str) -> Tui<TestBackend, IntoIter<char>> {
+    let terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    Tui::new(terminal, keys.chars().collect::<Vec<char>>().into_iter())
+}
+
+fn screen(tui: &Tui<TestBackend, IntoIter<char>>) -> String {
+    let buffer = tui.backend().buffer();
+    let width = buffer.area.width as usize;
+    buffer
+        .content()
+        .chunks(width)
+        .map(|row| row.iter().map(|c| c.symbol()).collect::<String>() + "\n")
+        .collect()
+}
+
+fn play(tui: &mut Tui<TestBackend, IntoIter<char>>, deck: &str, bankroll: &mut Bankroll) {
+    let mut deck: Deck = deck.parse().unwrap();
+    Blackjack::<_, _, _, Hand<Card>>::new(tui, &mut deck, bankroll, Rules::default()).start();
+}
+
+#[test]
+fn play_round() {
+    let mut tui = tui("20\nhs");
+    let mut bankroll = Bankroll::new(1000);
+    play(&mut tui, "5H 10S 6D 6C 9S 10D", &mut bankroll);
+    let screen = screen(&tui);
+    assert_eq!(1020, bankroll.balance());
+    assert!(screen.contains("Dealer (bust)"));
+    assert!(screen.contains("You (20)"));
+    assert!(screen.contains("|5    | |6    | |9    |"));
+    assert!(screen.contains("Balance: 1020"));
+    assert!(screen.contains("The dealer busts!"));
+    assert!(screen.contains("You win!"));
+}
+
+#[test]
+fn ignore_keys_not_offered() {
+    let mut tui = tui("dxs");
+    let action =
+        Interface::<Hand<Card>>::get_action(&mut tui, &[Action::PlayerHit, Action::PlayerStay]);
+    assert_eq!(Action::PlayerStay, action);
+}
+
+#[test]
+fn type_wager() {
+    let keys = format!("5{}1{}x7{}25{}", ENTER, BACKSPACE, ENTER, ENTER);
+    let mut tui = tui(&keys);
+    assert_eq!(25, Interface::<Hand<Card>>::get_wager(&mut tui, 10, 500));
+}
+
+#[test]
+fn scroll_event_log() {
+    let mut tui = tui("");
+    for _ in 0..30 {
+        Interface::<Hand<Card>>::send(&mut tui, Event::DealerHit);
+    }
+    Interface::<Hand<Card>>::send(&mut tui, Event::DealerStay);
+    assert!(screen(&tui).contains("The dealer stays."));
+}
+
+#[test]
+fn ask_play_again() {
+    let mut tui = tui("xY");
+    assert!(Interface::<Hand<Card>>::play_again(&mut tui));
+}
+
+#[test]
+fn quit_on_q() {
+    let mut tui = tui("qy");
+    assert_eq!(
+        Action::PlayerStay,
+        Interface::<Hand<Card>>::get_action(&mut tui, &[Action::PlayerHit, Action::PlayerStay])
+    );
+    assert!(!Interface::<Hand<Card>>::play_again(&mut tui));
+}
+
+#[test]
+fn quit_on_escape() {
+    let keys = format!("2{}5{}", ESCAPE, ENTER);
+    let mut tui = tui(&keys);
+    assert_eq!(10, Interface::<Hand<Card>>::get_wager(&mut tui, 10, 500));
+    assert_eq!(0, Interface::<Hand<Card>>::get_insurance(&mut tui, 5));
+    assert!(!Interface::<Hand<Card>>::play_again(&mut tui));
+}
+
+#[test]
+fn quit_when_keys_run_out() {
+    let mut tui = tui("2");
+    let mut bankroll = Bankroll::new(100);
+    play(&mut tui, "10H 10S 6D 9C", &mut bankroll);
+    assert!(!Interface::<Hand<Card>>::play_again(&mut tui));
+}